@@ -0,0 +1,111 @@
+//! `ScalarPrimitive` benchmarks.
+//!
+//! Run with `cargo bench --features dev`.
+
+// Benchmarks aren't subject to the MSRV, and need `black_box` (Rust 1.66)
+#![allow(clippy::incompatible_msrv)]
+
+use elliptic_curve::{
+    bigint::{Random, U256},
+    dev::ScalarPrimitive,
+};
+use rand_core::OsRng;
+use std::{hint::black_box, time::Instant};
+
+/// Run `f` repeatedly, print the mean time per iteration and return the
+/// result of the last one.
+fn bench<T>(name: &str, iters: u32, mut f: impl FnMut() -> T) -> T {
+    let mut ret = f();
+    let start = Instant::now();
+
+    for _ in 0..iters {
+        ret = black_box(f());
+    }
+
+    println!("{:<40} {:>12.2?}/iter", name, start.elapsed() / iters);
+    ret
+}
+
+/// Compare [`ScalarPrimitive::dot_product`] with repeated
+/// [`ScalarPrimitive::mul_add`], which reduces after every term.
+fn dot_product() {
+    for len in [4, 16, 64, 256] {
+        let a: Vec<_> = (0..len)
+            .map(|_| ScalarPrimitive::random(&mut OsRng))
+            .collect();
+        let b: Vec<_> = (0..len)
+            .map(|_| ScalarPrimitive::random(&mut OsRng))
+            .collect();
+
+        let dot = bench(&format!("dot_product/{}", len), 10_000, || {
+            ScalarPrimitive::dot_product(black_box(&a), black_box(&b)).unwrap()
+        });
+        let mul_add = bench(&format!("mul_add/{}", len), 10_000, || {
+            black_box(&a)
+                .iter()
+                .zip(black_box(&b))
+                .fold(ScalarPrimitive::ZERO, |acc, (x, y)| x.mul_add(y, &acc))
+        });
+
+        assert_eq!(dot, mul_add);
+    }
+}
+
+/// Compare reducing double-width integers with a precomputed
+/// [`BarrettContext`][`elliptic_curve::scalar::BarrettContext`] against the
+/// one-shot [`ScalarPrimitive::reduce_wide_uint`].
+fn reduce_wide() {
+    let wide: Vec<_> = (0..64)
+        .map(|_| U256::random(&mut OsRng).concat(&U256::random(&mut OsRng)))
+        .collect();
+    let ctx = bench(
+        "reduction_context",
+        1_000,
+        ScalarPrimitive::reduction_context,
+    );
+
+    let barrett = bench("reduce_wide_with/64", 1_000, || {
+        black_box(&wide)
+            .iter()
+            .map(|x| ScalarPrimitive::reduce_wide_with(&ctx, x))
+            .fold(ScalarPrimitive::ZERO, |acc, x| acc + x)
+    });
+    let one_shot = bench("reduce_wide_uint/64", 1_000, || {
+        black_box(&wide)
+            .iter()
+            .map(|x| ScalarPrimitive::reduce_wide_uint(*x))
+            .fold(ScalarPrimitive::ZERO, |acc, x| acc + x)
+    });
+
+    assert_eq!(barrett, one_shot);
+}
+
+/// Compare [`ScalarPrimitive::batch_from_be_slice`], which decodes batches
+/// in parallel when the `rayon` feature is enabled, with decoding each scalar
+/// serially.
+fn batch_from_be_slice() {
+    for len in [64, 256, 1024, 4096, 16384, 65536] {
+        let data: Vec<u8> = (0..len)
+            .flat_map(|_| ScalarPrimitive::random(&mut OsRng).to_be_bytes())
+            .collect();
+
+        let batch = bench(&format!("batch_from_be_slice/{}", len), 100, || {
+            ScalarPrimitive::batch_from_be_slice(black_box(&data)).unwrap()
+        });
+        let serial = bench(&format!("from_be_slice/{}", len), 100, || {
+            black_box(&data)
+                .chunks_exact(ScalarPrimitive::BYTES)
+                .map(ScalarPrimitive::from_be_slice)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        });
+
+        assert_eq!(batch, serial);
+    }
+}
+
+fn main() {
+    dot_product();
+    reduce_wide();
+    batch_from_be_slice();
+}
@@ -14,6 +14,7 @@ use crate::{
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
     AffineXCoordinate, AffineYIsOdd, Curve, CurveArithmetic, IsHigh, PrimeCurve,
+    ScalarSqrtParameters,
 };
 use core::{
     iter::{Product, Sum},
@@ -73,6 +74,10 @@ impl Curve for MockCurve {
 
 impl PrimeCurve for MockCurve {}
 
+impl ScalarSqrtParameters for MockCurve {
+    const Z: ScalarPrimitive = ScalarPrimitive::new_unchecked(U256::from_u64(7));
+}
+
 impl CurveArithmetic for MockCurve {
     type AffinePoint = AffinePoint;
     type ProjectivePoint = ProjectivePoint;
@@ -24,6 +24,13 @@ impl From<base16ct::Error> for Error {
     }
 }
 
+#[cfg(feature = "der")]
+impl From<der::Error> for Error {
+    fn from(_: der::Error) -> Error {
+        Error
+    }
+}
+
 #[cfg(feature = "pkcs8")]
 impl From<pkcs8::Error> for Error {
     fn from(_: pkcs8::Error) -> Error {
@@ -40,3 +47,36 @@ impl From<sec1::Error> for Error {
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
+
+/// Errors which can occur when parsing a scalar from a string, as returned
+/// by [`ScalarPrimitive::from_hex_detailed`][`crate::ScalarPrimitive::from_hex_detailed`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ScalarParseError {
+    /// The string contains characters which aren't valid hexadecimal.
+    InvalidHex,
+
+    /// The string doesn't have the length of a serialized scalar.
+    WrongLength,
+
+    /// The value overflows the scalar modulus.
+    OutOfRange,
+}
+
+impl Display for ScalarParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidHex => "invalid hexadecimal scalar encoding",
+            Self::WrongLength => "invalid scalar length",
+            Self::OutOfRange => "scalar out of range",
+        })
+    }
+}
+
+impl From<ScalarParseError> for Error {
+    fn from(_: ScalarParseError) -> Error {
+        Error
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ScalarParseError {}
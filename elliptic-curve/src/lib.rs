@@ -95,7 +95,7 @@ mod public_key;
 mod jwk;
 
 pub use crate::{
-    error::{Error, Result},
+    error::{Error, Result, ScalarParseError},
     point::{
         AffineXCoordinate, AffineYIsOdd, DecompactPoint, DecompressPoint, PointCompaction,
         PointCompression,
@@ -123,6 +123,9 @@ pub use {
 #[cfg(feature = "bits")]
 pub use crate::scalar::ScalarBits;
 
+#[cfg(feature = "der")]
+pub use der;
+
 #[cfg(feature = "jwk")]
 pub use crate::jwk::{JwkEcKey, JwkParameters};
 
@@ -173,6 +176,17 @@ pub trait Curve: 'static + Copy + Clone + Debug + Default + Eq + Ord + Send + Sy
 /// Marker trait for elliptic curves with prime order.
 pub trait PrimeCurve: Curve {}
 
+/// Parameters for computing square roots modulo the order of a curve with
+/// prime order, e.g. with [`ScalarPrimitive::sqrt`].
+pub trait ScalarSqrtParameters: PrimeCurve {
+    /// Non-square `Z` modulo the curve order, used by
+    /// [`ScalarPrimitive::sqrt_ratio`] as described in
+    /// [RFC 9380 Section 5.2](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2).
+    ///
+    /// This is typically the smallest non-square integer `Z >= 2`.
+    const Z: ScalarPrimitive<Self>;
+}
+
 /// Size of field elements of this elliptic curve.
 pub type FieldSize<C> = <<C as Curve>::Uint as bigint::ArrayEncoding>::ByteSize;
 
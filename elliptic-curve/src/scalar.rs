@@ -1,14 +1,36 @@
 //! Scalar types.
 
+mod add_chain;
+mod barrett;
+#[cfg(feature = "base58")]
+mod base58check;
+mod bit_stream;
 #[cfg(feature = "arithmetic")]
 mod blinded;
+mod glv;
 #[cfg(feature = "arithmetic")]
 mod invert;
 #[cfg(feature = "arithmetic")]
 mod nonzero;
+#[cfg(feature = "alloc")]
+mod pow_table;
 mod primitive;
+mod wide;
 
-pub use self::primitive::ScalarPrimitive;
+#[cfg(feature = "alloc")]
+pub mod checksummed;
+#[cfg(feature = "serde")]
+pub mod serde_minimal;
+
+#[cfg(feature = "alloc")]
+pub use self::pow_table::PowTable;
+pub use self::{
+    add_chain::{AddChain, AddChainStep},
+    barrett::BarrettContext,
+    bit_stream::{BitReader, BitWriter},
+    glv::GlvBasis,
+    primitive::{ScalarEditGuard, ScalarPrimitive},
+};
 #[cfg(feature = "arithmetic")]
 pub use self::{blinded::BlindedScalar, invert::invert_vartime, nonzero::NonZeroScalar};
 
@@ -18,6 +40,9 @@ use subtle::Choice;
 #[cfg(feature = "arithmetic")]
 use crate::CurveArithmetic;
 
+#[cfg(feature = "serde")]
+use crate::Curve;
+
 /// Scalar field element for a particular elliptic curve.
 #[cfg(feature = "arithmetic")]
 pub type Scalar<C> = <C as CurveArithmetic>::Scalar;
@@ -26,6 +51,28 @@ pub type Scalar<C> = <C as CurveArithmetic>::Scalar;
 #[cfg(feature = "bits")]
 pub type ScalarBits<C> = ff::FieldBits<<Scalar<C> as ff::PrimeFieldBits>::ReprBits>;
 
+/// Is the given [`ScalarPrimitive`] equal to zero?
+///
+/// Intended for use as a `skip_serializing_if` predicate for optional scalar
+/// fields which default to zero. Since the [`Default`] value of
+/// [`ScalarPrimitive`] is [`ScalarPrimitive::ZERO`], omitted fields are
+/// reconstructed as zero when deserialized with `#[serde(default)]`:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Message<C: Curve> {
+///     #[serde(default, skip_serializing_if = "elliptic_curve::scalar::is_zero")]
+///     blinding: ScalarPrimitive<C>,
+/// }
+/// ```
+///
+/// Unlike [`ScalarPrimitive::is_zero`], this returns a [`bool`], so it is not
+/// constant-time.
+#[cfg(feature = "serde")]
+pub fn is_zero<C: Curve>(scalar: &ScalarPrimitive<C>) -> bool {
+    scalar.is_zero().into()
+}
+
 /// Instantiate a scalar from an unsigned integer without checking for overflow.
 pub trait FromUintUnchecked {
     /// Unsigned integer type (i.e. `Curve::Uint`)
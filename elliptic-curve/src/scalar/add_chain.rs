@@ -0,0 +1,117 @@
+//! Addition chains for fixed-exponent exponentiation of [`ScalarPrimitive`].
+
+use super::ScalarPrimitive;
+use crate::Curve;
+use subtle::CtOption;
+
+/// Single step of an [`AddChain`], operating on its registers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AddChainStep {
+    /// Set register `dst` to register `src` squared `times` times, i.e.
+    /// `r[dst] = r[src]^(2^times)`. With `times = 0` this copies `src`.
+    Square {
+        /// Destination register.
+        dst: usize,
+
+        /// Source register.
+        src: usize,
+
+        /// Number of squarings.
+        times: u32,
+    },
+
+    /// Set register `dst` to the product of registers `a` and `b`, i.e.
+    /// `r[dst] = r[a] * r[b]`.
+    Mul {
+        /// Destination register.
+        dst: usize,
+
+        /// First factor register.
+        a: usize,
+
+        /// Second factor register.
+        b: usize,
+    },
+}
+
+/// Addition chain computing a fixed power of a scalar, e.g. the exponent
+/// `n - 2` used for inversion by Fermat's little theorem.
+///
+/// The chain operates on [`AddChain::REGISTERS`] registers which are all
+/// initialized to the base. Its steps are evaluated in order, and the result
+/// is the final value of register `0`.
+///
+/// Curves can supply a chain tuned for their specific order to
+/// [`ScalarPrimitive::invert_fermat_with_chain`].
+#[derive(Copy, Clone, Debug)]
+pub struct AddChain<'a> {
+    steps: &'a [AddChainStep],
+}
+
+impl<'a> AddChain<'a> {
+    /// Number of registers available to the chain.
+    pub const REGISTERS: usize = 16;
+
+    /// Create a new addition chain from the given steps.
+    pub const fn new(steps: &'a [AddChainStep]) -> Self {
+        Self { steps }
+    }
+
+    /// Get the steps of this chain.
+    pub fn steps(&self) -> &'a [AddChainStep] {
+        self.steps
+    }
+}
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Raise this scalar to the fixed power computed by `chain`.
+    ///
+    /// The running time depends only on the chain, so this is constant-time
+    /// with respect to the scalar.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any step refers to a register outside of
+    /// [`AddChain::REGISTERS`].
+    pub fn pow_chain(&self, chain: &AddChain<'_>) -> Self {
+        let mut r = [*self; AddChain::REGISTERS];
+
+        for step in chain.steps {
+            match *step {
+                AddChainStep::Square { dst, src, times } => {
+                    let mut x = r[src];
+
+                    for _ in 0..times {
+                        x *= x;
+                    }
+
+                    r[dst] = x;
+                }
+                AddChainStep::Mul { dst, a, b } => r[dst] = r[a] * r[b],
+            }
+        }
+
+        r[0]
+    }
+
+    /// Compute the multiplicative inverse of this scalar using Fermat's
+    /// little theorem, with a curve-specific `chain` for the exponent `n - 2`.
+    ///
+    /// This computes the same result as [`ScalarPrimitive::invert_fermat`]
+    /// when `chain` is correct, but an optimized chain needs considerably
+    /// fewer multiplications. The chain is not checked: an incorrect chain
+    /// returns a meaningless result.
+    ///
+    /// Returns none if the scalar is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any step refers to a register outside of
+    /// [`AddChain::REGISTERS`].
+    pub fn invert_fermat_with_chain(&self, chain: &AddChain<'_>) -> CtOption<Self> {
+        CtOption::new(self.pow_chain(chain), !self.is_zero())
+    }
+}
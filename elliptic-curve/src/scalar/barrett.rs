@@ -0,0 +1,79 @@
+//! Barrett reduction of double-width integers modulo the order of a
+//! [`ScalarPrimitive`].
+
+use super::ScalarPrimitive;
+use crate::{
+    bigint::{Concat, Integer, NonZero, Split, Zero},
+    scalar::FromUintUnchecked,
+    Curve,
+};
+
+/// Precomputed parameters for reducing double-width integers modulo the
+/// scalar order `n` using Barrett reduction.
+///
+/// Computing the parameters requires a (variable-time) division, which only
+/// depends on the order. Creating a context once with
+/// [`ScalarPrimitive::reduction_context`] amortizes its cost over many calls
+/// to [`ScalarPrimitive::reduce_wide_with`].
+#[derive(Copy, Clone, Debug)]
+pub struct BarrettContext<C: Curve>
+where
+    C::Uint: Concat,
+    <C::Uint as Concat>::Output: Integer,
+{
+    /// Scalar order `n`, widened to double width.
+    modulus: <C::Uint as Concat>::Output,
+
+    /// Barrett constant `mu = floor((2^(2 * BITS) - 1) / n)`.
+    mu: <C::Uint as Concat>::Output,
+}
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Precompute the parameters used by [`ScalarPrimitive::reduce_wide_with`].
+    pub fn reduction_context<W>() -> BarrettContext<C>
+    where
+        C::Uint: Concat<Output = W>,
+        W: Integer,
+    {
+        let modulus = C::Uint::ZERO.concat(&Self::MODULUS);
+        let mu = W::MAX / NonZero::new(modulus).unwrap();
+        BarrettContext { modulus, mu }
+    }
+
+    /// Reduce a double-width integer modulo [`ScalarPrimitive::MODULUS`]
+    /// using the precomputed parameters in `ctx`.
+    ///
+    /// This computes the same result as [`ScalarPrimitive::reduce_wide_uint`],
+    /// but using a fixed sequence of multiplications and conditional
+    /// subtractions instead of a division, so it is also constant-time.
+    pub fn reduce_wide_with<W, W2>(ctx: &BarrettContext<C>, wide: &W) -> Self
+    where
+        C::Uint: Concat<Output = W>,
+        W: Integer + Concat<Output = W2> + Split<Output = C::Uint>,
+        W2: Integer + Split<Output = W>,
+    {
+        // `q = floor(wide * mu / 2^(2 * BITS))` underestimates `wide / n` by
+        // less than 3, so the remainder needs at most two corrections.
+        let product = W::ZERO
+            .concat(wide)
+            .checked_mul(&W::ZERO.concat(&ctx.mu))
+            .unwrap();
+        let (q, _) = product.split();
+        let mut r = wide
+            .checked_sub(&q.checked_mul(&ctx.modulus).unwrap())
+            .unwrap();
+
+        for _ in 0..2 {
+            r = r.checked_sub(&ctx.modulus).unwrap_or(r);
+        }
+
+        let (hi, lo) = r.split();
+        debug_assert!(bool::from(hi.is_zero()));
+        let ret = Self::from_uint_unchecked(lo);
+        ret.debug_assert_canonical();
+        ret
+    }
+}
@@ -0,0 +1,127 @@
+//! Base58Check encoding of [`ScalarPrimitive`], e.g. for wallet import
+//! formats.
+//!
+//! The encoded payload consists of a version byte followed by the fixed-width
+//! big endian scalar and the first four bytes of its double SHA-256 digest
+//! as a checksum.
+//!
+//! ⚠️ WARNING: encoding and decoding are not constant-time.
+
+use super::ScalarPrimitive;
+use crate::{Curve, Error, Result};
+use alloc::{string::String, vec::Vec};
+use sha2::{Digest, Sha256};
+
+/// Base58 alphabet, indexed by digit value.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Length of the checksum in bytes.
+const CHECKSUM_LEN: usize = 4;
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Encode this scalar as a Base58Check string with the given `version`
+    /// byte.
+    pub fn to_base58check(&self, version: u8) -> String {
+        let mut payload = Vec::with_capacity(1 + Self::BYTES + CHECKSUM_LEN);
+        payload.push(version);
+        payload.extend_from_slice(&self.to_be_bytes());
+
+        let checksum = checksum(&payload);
+        payload.extend_from_slice(&checksum);
+        encode(&payload)
+    }
+
+    /// Decode a Base58Check string produced by
+    /// [`ScalarPrimitive::to_base58check`], returning its version byte along
+    /// with the scalar.
+    ///
+    /// Returns an error if the string isn't valid Base58, if the checksum or
+    /// length are invalid, or if the decoded value overflows the modulus.
+    pub fn from_base58check(s: &str) -> Result<(u8, Self)> {
+        let payload = decode(s).ok_or(Error)?;
+
+        if payload.len() != 1 + Self::BYTES + CHECKSUM_LEN {
+            return Err(Error);
+        }
+
+        let (data, expected) = payload.split_at(1 + Self::BYTES);
+
+        if checksum(data) != expected {
+            return Err(Error);
+        }
+
+        Ok((data[0], Self::from_be_slice(&data[1..])?))
+    }
+}
+
+/// Compute the first four bytes of the double SHA-256 digest of `data`.
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let digest = Sha256::digest(Sha256::digest(data));
+    let mut ret = [0; CHECKSUM_LEN];
+    ret.copy_from_slice(&digest[..CHECKSUM_LEN]);
+    ret
+}
+
+/// Encode `data` as Base58, preserving leading zero bytes as `1`s.
+fn encode(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|&&b| b == 0).count();
+
+    // Little endian base 58 digits
+    let mut digits = Vec::<u8>::with_capacity(data.len() * 138 / 100 + 1);
+
+    for &byte in &data[zeros..] {
+        let mut carry = u32::from(byte);
+
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut ret = String::with_capacity(zeros + digits.len());
+    ret.extend((0..zeros).map(|_| '1'));
+    ret.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| char::from(ALPHABET[d as usize])),
+    );
+    ret
+}
+
+/// Decode a Base58 string, returning none if it contains invalid characters.
+fn decode(s: &str) -> Option<Vec<u8>> {
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+
+    // Little endian bytes
+    let mut bytes = Vec::<u8>::with_capacity(s.len() * 733 / 1000 + 1);
+
+    for c in s.bytes().skip(zeros) {
+        let mut carry = ALPHABET.iter().position(|&d| d == c)? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut ret = Vec::with_capacity(zeros + bytes.len());
+    ret.resize(zeros, 0);
+    ret.extend(bytes.iter().rev());
+    Some(ret)
+}
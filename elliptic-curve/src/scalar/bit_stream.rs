@@ -0,0 +1,119 @@
+//! Bit-packed encoding of [`ScalarPrimitive`].
+//!
+//! Scalars are written using exactly [`ScalarPrimitive::bits_needed`] bits,
+//! least significant bit first, without any byte alignment. Within each
+//! byte of the underlying buffer, bits are filled from the least significant
+//! bit upwards.
+
+use super::ScalarPrimitive;
+use crate::{bigint::Zero, Curve};
+use subtle::{Choice, CtOption};
+
+/// Writer for a stream of bits over a byte buffer.
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    /// Output buffer.
+    buf: &'a mut [u8],
+
+    /// Number of bits written so far.
+    pos: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Create a new writer which writes bits to `buf`, starting from its
+    /// first byte.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Get the number of bits written so far.
+    pub fn bits_written(&self) -> usize {
+        self.pos
+    }
+
+    /// Write a single bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is full.
+    fn write_bit(&mut self, bit: Choice) {
+        let byte = &mut self.buf[self.pos / 8];
+        let shift = self.pos % 8;
+        *byte = (*byte & !(1 << shift)) | (bit.unwrap_u8() << shift);
+        self.pos += 1;
+    }
+}
+
+/// Reader for a stream of bits over a byte buffer.
+#[derive(Clone, Debug)]
+pub struct BitReader<'a> {
+    /// Input buffer.
+    buf: &'a [u8],
+
+    /// Number of bits read so far.
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Create a new reader which reads bits from `buf`, starting from its
+    /// first byte.
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Get the number of bits read so far.
+    pub fn bits_read(&self) -> usize {
+        self.pos
+    }
+
+    /// Get the number of bits remaining.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() * 8 - self.pos
+    }
+
+    /// Read a single bit, assuming at least one bit remains.
+    fn read_bit(&mut self) -> Choice {
+        let bit = (self.buf[self.pos / 8] >> (self.pos % 8)) & 1;
+        self.pos += 1;
+        Choice::from(bit)
+    }
+}
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Write this scalar to `out` as exactly
+    /// [`ScalarPrimitive::bits_needed`] bits, least significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` doesn't have enough space remaining.
+    pub fn write_bits_le(&self, out: &mut BitWriter<'_>) {
+        for i in 0..Self::bits_needed() {
+            out.write_bit(self.bit(i));
+        }
+    }
+
+    /// Read a scalar written by [`ScalarPrimitive::write_bits_le`] from
+    /// `reader`, consuming exactly [`ScalarPrimitive::bits_needed`] bits.
+    ///
+    /// Returns none if the decoded value overflows the modulus, or if fewer
+    /// bits remain in `reader`, in which case no bits are consumed.
+    pub fn from_bits_le_stream(reader: &mut BitReader<'_>) -> CtOption<Self> {
+        let bits = Self::bits_needed();
+
+        if reader.remaining() < bits {
+            return CtOption::new(Self::ZERO, Choice::from(0));
+        }
+
+        let mut inner = C::Uint::ZERO;
+
+        for i in 0..bits {
+            let bit = C::Uint::from(u64::from(reader.read_bit().unwrap_u8()));
+            inner = inner | (bit << i);
+        }
+
+        Self::new(inner)
+    }
+}
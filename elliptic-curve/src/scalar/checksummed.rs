@@ -0,0 +1,135 @@
+//! Checksummed encoding of [`ScalarPrimitive`] for manual entry.
+//!
+//! Scalars are encoded using [Bech32] with the human-readable part
+//! [`HRP`], e.g. `scalar1qqqq...`. The encoding is case-insensitive and its
+//! checksum is guaranteed to detect up to four substituted characters in
+//! strings of at most 90 characters, making it suitable for scalars which
+//! are transcribed by hand, e.g. on paper backups.
+//!
+//! This covers scalars of up to 48 bytes, i.e. curves up to P-384. Longer
+//! encodings, e.g. of 66-byte P-521 scalars, are still accepted, but the
+//! guarantee doesn't hold for them: errors are only detected with high
+//! probability.
+//!
+//! ⚠️ WARNING: encoding and decoding are not constant-time.
+//!
+//! [Bech32]: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+
+use super::ScalarPrimitive;
+use crate::{Curve, Error, Result};
+use alloc::{string::String, vec::Vec};
+
+/// Human-readable part of the encoding.
+pub const HRP: &str = "scalar";
+
+/// Bech32 character set, indexed by 5-bit value.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Length of the checksum in characters.
+const CHECKSUM_LEN: usize = 6;
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Encode this scalar as a lower case Bech32 string with the
+    /// human-readable part [`HRP`].
+    pub fn to_base32_checksummed(&self) -> String {
+        let mut data = regroup(&self.to_be_bytes(), 8, 5);
+        let checksum = polymod(&data, &[0; CHECKSUM_LEN]) ^ 1;
+        data.extend((0..CHECKSUM_LEN).map(|i| (checksum >> (5 * (5 - i)) & 31) as u8));
+
+        let mut ret = String::with_capacity(HRP.len() + 1 + data.len());
+        ret.push_str(HRP);
+        ret.push('1');
+        ret.extend(data.iter().map(|&d| char::from(CHARSET[d as usize])));
+        ret
+    }
+
+    /// Decode a scalar from a Bech32 string produced by
+    /// [`ScalarPrimitive::to_base32_checksummed`].
+    ///
+    /// Upper case input is accepted, but mixed case is not. Returns an error
+    /// if the human-readable part, checksum or length are invalid, or if the
+    /// decoded value overflows the modulus.
+    pub fn from_base32_checksummed(s: &str) -> Result<Self> {
+        if s.bytes().any(|c| c.is_ascii_lowercase()) && s.bytes().any(|c| c.is_ascii_uppercase()) {
+            return Err(Error);
+        }
+
+        let s = s.to_ascii_lowercase();
+        let data = s
+            .strip_prefix(HRP)
+            .and_then(|rest| rest.strip_prefix('1'))
+            .ok_or(Error)?
+            .bytes()
+            .map(|c| CHARSET.iter().position(|&d| d == c).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(Error)?;
+
+        if data.len() < CHECKSUM_LEN || polymod(&data, &[]) != 1 {
+            return Err(Error);
+        }
+
+        let data = &data[..(data.len() - CHECKSUM_LEN)];
+        let bytes = regroup(data, 5, 8);
+
+        // Reject non-zero padding bits, which are dropped by `regroup`
+        if bytes.len() != Self::BYTES || regroup(&bytes, 8, 5) != data {
+            return Err(Error);
+        }
+
+        Self::from_be_slice(&bytes)
+    }
+}
+
+/// Convert between groups of `from` and `to` bits, padding the final group
+/// with zeros when widening to fewer bits and dropping incomplete groups
+/// otherwise.
+fn regroup(data: &[u8], from: u32, to: u32) -> Vec<u8> {
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut ret = Vec::with_capacity((data.len() * from as usize + to as usize - 1) / to as usize);
+
+    for &value in data {
+        acc = (acc << from) | u32::from(value);
+        bits += from;
+
+        while bits >= to {
+            bits -= to;
+            ret.push(((acc >> bits) & ((1 << to) - 1)) as u8);
+        }
+    }
+
+    if to < from && bits > 0 {
+        ret.push(((acc << (to - bits)) & ((1 << to) - 1)) as u8);
+    }
+
+    ret
+}
+
+/// Compute the Bech32 checksum polynomial over the expanded [`HRP`] followed
+/// by `data` and `suffix`.
+fn polymod(data: &[u8], suffix: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let hrp = HRP.bytes();
+    let values = hrp
+        .clone()
+        .map(|c| c >> 5)
+        .chain([0])
+        .chain(hrp.map(|c| c & 31))
+        .chain(data.iter().copied())
+        .chain(suffix.iter().copied());
+
+    values.fold(1, |chk, value| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x1ffffff) << 5) ^ u32::from(value);
+
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(chk, |chk, (_, g)| chk ^ g)
+    })
+}
@@ -0,0 +1,97 @@
+//! Scalar decomposition for the Gallant-Lambert-Vanstone (GLV) method.
+
+use super::ScalarPrimitive;
+use crate::{
+    bigint::{Concat, Integer, NonZero, Split, Zero},
+    Curve, IsHigh,
+};
+use subtle::ConditionallySelectable;
+
+/// Short lattice basis `((a1, b1), (a2, b2))` used for GLV scalar
+/// decomposition, where `a_i + b_i * lambda = 0 (mod n)` for the eigenvalue
+/// `lambda` of the curve's endomorphism.
+///
+/// The basis is specific to each curve and must be supplied by the caller.
+/// Each (possibly negative) component is represented as a scalar modulo `n`,
+/// e.g. `-x` as `n - x`.
+#[derive(Copy, Clone, Debug)]
+pub struct GlvBasis<C: Curve> {
+    /// First component of the first basis vector.
+    pub a1: ScalarPrimitive<C>,
+
+    /// Second component of the first basis vector.
+    pub b1: ScalarPrimitive<C>,
+
+    /// First component of the second basis vector.
+    pub a2: ScalarPrimitive<C>,
+
+    /// Second component of the second basis vector.
+    pub b2: ScalarPrimitive<C>,
+}
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Decompose this scalar `k` into two signed sub-scalars `k1` and `k2`
+    /// such that `k = k1 + k2 * lambda (mod n)`, using the given lattice
+    /// `basis`.
+    ///
+    /// Returns `(k1_is_negative, |k1|, k2_is_negative, |k2|)`. For a suitably
+    /// short basis, both magnitudes are roughly half the size of `n`.
+    ///
+    /// ⚠️ WARNING: the returned signs are [`bool`]s, so the caller must take
+    /// care not to branch on them when the scalar is secret.
+    pub fn decompose_glv<W>(&self, basis: &GlvBasis<C>) -> (bool, Self, bool, Self)
+    where
+        C::Uint: Concat<Output = W>,
+        W: Integer + Split<Output = C::Uint>,
+    {
+        // `c1 = round(b2 * k / n)` and `c2 = round(-b1 * k / n)`
+        let c1 = self.mul_div_round(&basis.b2);
+        let c2 = self.mul_div_round(&-basis.b1);
+
+        let k1 = *self - c1 * basis.a1 - c2 * basis.a2;
+        let k2 = -(c1 * basis.b1) - c2 * basis.b2;
+
+        let (k1_neg, k1) = k1.signed_magnitude();
+        let (k2_neg, k2) = k2.signed_magnitude();
+        (k1_neg, k1, k2_neg, k2)
+    }
+
+    /// Compute `round(self * b / n)`, where `b` is a signed scalar.
+    fn mul_div_round<W>(&self, b: &Self) -> Self
+    where
+        C::Uint: Concat<Output = W>,
+        W: Integer + Split<Output = C::Uint>,
+    {
+        let widen = |x: &C::Uint| C::Uint::ZERO.concat(x);
+        let (b_neg, b) = b.signed_magnitude();
+        let modulus = widen(&Self::MODULUS);
+
+        // Neither operation can overflow since both operands are less than `n`
+        let product = widen(self.as_uint())
+            .checked_mul(&widen(b.as_uint()))
+            .unwrap();
+        let product = product.checked_add(&(modulus >> 1)).unwrap();
+
+        let (_, quotient) = (product / NonZero::new(modulus).unwrap()).split();
+        let c = Self::new(quotient).unwrap();
+
+        if b_neg {
+            -c
+        } else {
+            c
+        }
+    }
+
+    /// Interpret this scalar as a signed integer in `(-n/2, n/2]`, returning
+    /// whether it is negative along with its magnitude.
+    fn signed_magnitude(&self) -> (bool, Self) {
+        let is_neg = self.is_high();
+        (
+            is_neg.into(),
+            Self::conditional_select(self, &-self, is_neg),
+        )
+    }
+}
@@ -0,0 +1,92 @@
+//! Precomputed tables for fixed-base exponentiation of [`ScalarPrimitive`].
+
+use super::ScalarPrimitive;
+use crate::Curve;
+use alloc::vec::Vec;
+
+/// Table of the powers `base^0, base^1, ..., base^(2^window - 1)` of a
+/// fixed base, used to speed up exponentiation to many different public
+/// exponents.
+///
+/// Created with [`ScalarPrimitive::precompute_pow_table`] and used with
+/// [`ScalarPrimitive::pow_vartime_table`].
+#[derive(Clone, Debug)]
+pub struct PowTable<C: Curve> {
+    /// Window size in bits.
+    window: u32,
+
+    /// Powers of the base, indexed by exponent.
+    powers: Vec<ScalarPrimitive<C>>,
+}
+
+impl<C> PowTable<C>
+where
+    C: Curve,
+{
+    /// Get the window size in bits.
+    pub fn window(&self) -> u32 {
+        self.window
+    }
+}
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Precompute a table of `2^window` powers of this scalar for use with
+    /// [`ScalarPrimitive::pow_vartime_table`].
+    ///
+    /// Larger windows need fewer multiplications per exponentiation at the
+    /// cost of an exponentially larger table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not in the range `1..=8`.
+    pub fn precompute_pow_table(&self, window: u32) -> PowTable<C> {
+        assert!((1..=8).contains(&window), "window must be in 1..=8");
+
+        let mut powers = Vec::with_capacity(1 << window);
+        powers.push(Self::ONE);
+
+        for i in 1..(1 << window) {
+            powers.push(powers[i - 1] * self);
+        }
+
+        PowTable { window, powers }
+    }
+
+    /// Raise the base of `table` to the power of `exp`, given as little
+    /// endian 64-bit words, using fixed-window exponentiation.
+    ///
+    /// ⚠️ WARNING: this operation is variable time with respect to the
+    /// exponent. Use it only with public exponents.
+    pub fn pow_vartime_table<S: AsRef<[u64]>>(table: &PowTable<C>, exp: S) -> Self {
+        let exp = exp.as_ref();
+        let window = table.window as usize;
+        let digits = (exp.len() * 64 + window - 1) / window;
+
+        let mut acc = Self::ONE;
+        let mut started = false;
+
+        for j in (0..digits).rev() {
+            if started {
+                for _ in 0..window {
+                    acc *= acc;
+                }
+            }
+
+            let digit = (0..window).rev().fold(0, |digit, k| {
+                let i = j * window + k;
+                let bit = exp.get(i / 64).map_or(0, |word| (word >> (i % 64)) & 1);
+                (digit << 1) | bit as usize
+            });
+
+            if digit != 0 {
+                acc *= table.powers[digit];
+                started = true;
+            }
+        }
+
+        acc
+    }
+}
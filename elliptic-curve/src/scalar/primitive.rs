@@ -2,141 +2,2324 @@
 
 use crate::{
     bigint::{prelude::*, Limb, NonZero},
-    ops::{Add, AddAssign, Neg, Shr1, Sub, SubAssign},
-    scalar::FromUintUnchecked,
-    Curve, Error, FieldBytes, IsHigh, Result,
+    ops::{Add, AddAssign, Mul, Neg, Shr1, Sub, SubAssign},
+    scalar::{
+        wide::{self, WideReducer},
+        FromUintUnchecked,
+    },
+    Curve, Error, FieldBytes, FieldSize, IsHigh, Result, ScalarParseError, ScalarSqrtParameters,
 };
 use base16ct::HexDisplay;
-use core::{cmp::Ordering, fmt, str};
-use generic_array::GenericArray;
-use rand_core::CryptoRngCore;
+use core::{
+    cmp::Ordering,
+    fmt, iter,
+    ops::{Deref, DerefMut, Div, DivAssign, MulAssign},
+    str,
+};
+use generic_array::{
+    sequence::GenericSequence,
+    typenum::{Prod, Quot, Sum, U2, U7, U8},
+    ArrayLength, GenericArray,
+};
+use rand_core::{CryptoRng, CryptoRngCore, RngCore};
 use subtle::{
     Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
     CtOption,
 };
 use zeroize::DefaultIsZeroes;
 
-#[cfg(feature = "arithmetic")]
-use super::{CurveArithmetic, Scalar};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "arithmetic")]
+use super::{CurveArithmetic, NonZeroScalar, Scalar};
+
+#[cfg(feature = "der")]
+use der::{asn1::UIntRef, Decode};
+
+#[cfg(feature = "digest")]
+use digest::Digest;
+
+#[cfg(feature = "jwk")]
+use base64ct::{Base64UrlUnpadded as Base64Url, Encoding as _};
+
+#[cfg(feature = "hash2curve")]
+use crate::hash2curve::{ExpandMsg, Expander};
+
+#[cfg(feature = "borsh")]
+use borsh::{
+    maybestd::io::{self, Read, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
+/// Minimum number of scalars [`ScalarPrimitive::batch_from_be_slice`]
+/// decodes in parallel.
+#[cfg(feature = "rayon")]
+const PAR_BATCH_MIN: usize = 1024;
+
+/// Generic scalar type with primitive functionality.
+///
+/// This type provides a baseline level of scalar arithmetic functionality
+/// which is always available for all curves, regardless of if they implement
+/// any arithmetic traits.
+///
+/// # `serde` support
+///
+/// When the optional `serde` feature of this create is enabled, [`Serialize`]
+/// and [`Deserialize`] impls are provided for this type.
+///
+/// The serialization is a fixed-width big endian encoding. When used with
+/// textual formats, the binary data is encoded as hexadecimal.
+///
+/// # `borsh` support
+///
+/// When the optional `borsh` feature of this crate is enabled,
+/// `BorshSerialize` and `BorshDeserialize` impls are provided for this type.
+///
+/// Following Borsh conventions, the serialization is a fixed-width little
+/// endian encoding.
+// TODO(tarcieri): use `crypto-bigint`'s `Residue` type, expose more functionality?
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ScalarPrimitive<C: Curve> {
+    /// Inner unsigned integer type.
+    inner: C::Uint,
+}
+
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Zero scalar.
+    pub const ZERO: Self = Self {
+        inner: C::Uint::ZERO,
+    };
+
+    /// Multiplicative identity.
+    pub const ONE: Self = Self {
+        inner: C::Uint::ONE,
+    };
+
+    /// Scalar modulus.
+    pub const MODULUS: C::Uint = C::ORDER;
+
+    /// Size of the serialized scalar in bytes.
+    pub const BYTES: usize = C::Uint::BYTES;
+
+    /// Size of the inner integer in bits.
+    pub const BITS: usize = C::Uint::BITS;
+
+    /// Generate a random [`ScalarPrimitive`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self {
+            inner: C::Uint::random_mod(rng, &NonZero::new(Self::MODULUS).unwrap()),
+        }
+    }
+
+    /// Generate a random [`ScalarPrimitive`] using the operating system's
+    /// random number generator, i.e. [`OsRng`][`rand_core::OsRng`].
+    ///
+    /// Use [`ScalarPrimitive::random`] to supply an explicit RNG, e.g. for
+    /// deterministic tests.
+    #[cfg(feature = "getrandom")]
+    pub fn from_entropy() -> Self {
+        Self::random(&mut rand_core::OsRng)
+    }
+
+    /// Generate a random [`ScalarPrimitive`] using rejection sampling, giving
+    /// up after `max_tries` attempts.
+    ///
+    /// Each attempt samples a random integer, masks it to
+    /// [`ScalarPrimitive::bits_needed`] bits and accepts it if it is less
+    /// than the modulus, exactly like [`ScalarPrimitive::random`]. With a
+    /// working RNG each attempt succeeds with probability at least 1/2, so
+    /// returning `None` indicates a (likely) broken entropy source rather
+    /// than an unbounded loop.
+    pub fn random_bounded(rng: &mut impl CryptoRngCore, max_tries: u32) -> Option<Self> {
+        let mask = C::Uint::MAX >> (Self::BITS - Self::bits_needed());
+
+        (0..max_tries).find_map(|_| Option::from(Self::new(C::Uint::random(&mut *rng) & mask)))
+    }
+
+    /// Generate a random [`ScalarPrimitive`] by sampling exactly
+    /// [`ScalarPrimitive::BYTES`] uniformly random bytes and reducing them
+    /// modulo the scalar modulus `n`.
+    ///
+    /// Unlike [`ScalarPrimitive::random`], which uses rejection sampling and
+    /// is therefore perfectly uniform, this always consumes a fixed amount of
+    /// randomness but produces a slightly biased output. With
+    /// `k = 8 * BYTES`, the statistical distance from uniform is at most
+    /// `(2^k mod n) / 2^k`, which is itself bounded by `2^-(k - bits(n))`.
+    ///
+    /// This is only negligible when `n` is very close to `2^k` (e.g. ~`2^-32`
+    /// for P-256) or much smaller than it. Use [`ScalarPrimitive::random`]
+    /// when in doubt.
+    pub fn random_reduce(rng: &mut impl CryptoRngCore) -> Self {
+        let mut bytes = FieldBytes::<C>::default();
+        rng.fill_bytes(&mut bytes);
+
+        let uint = C::Uint::from_be_byte_array(bytes);
+        let modulus = NonZero::new(Self::MODULUS).unwrap();
+
+        Self {
+            inner: uint_rem(&uint, &modulus),
+        }
+    }
+
+    /// Generate a random [`ScalarPrimitive`] using a fallible RNG, returning
+    /// any error from the RNG rather than panicking.
+    ///
+    /// Samples `2 * BYTES` bytes using [`RngCore::try_fill_bytes`] and reduces
+    /// them modulo the scalar modulus, which makes the bias negligible while
+    /// avoiding the unbounded loop of rejection sampling.
+    pub fn try_random<R: CryptoRng + RngCore>(
+        rng: &mut R,
+    ) -> core::result::Result<Self, rand_core::Error> {
+        let mut bytes = FieldBytes::<C>::default();
+        let mut ret = Self::ZERO;
+
+        for _ in 0..2 {
+            rng.try_fill_bytes(&mut bytes)?;
+            ret = bytes.iter().fold(ret, |acc, &byte| acc.shl8_add(byte));
+        }
+
+        Ok(ret)
+    }
+
+    /// Fill `out` with randomly chosen signs, i.e. either
+    /// [`ScalarPrimitive::ONE`] or its negation `MODULUS - 1`.
+    ///
+    /// Each sign is selected in constant time from a random bit.
+    pub fn random_signs(rng: &mut impl CryptoRngCore, out: &mut [Self]) {
+        let minus_one = -Self::ONE;
+
+        for sign in out {
+            let choice = Choice::from((rng.next_u32() & 1) as u8);
+            *sign = Self::conditional_select(&Self::ONE, &minus_one, choice);
+        }
+    }
+
+    /// Fill `out` with random, pairwise distinct, non-zero scalars, e.g. for
+    /// use as Shamir secret sharing evaluation points.
+    ///
+    /// Scalars are resampled on collision, so this loops forever if `out`
+    /// is longer than `MODULUS - 1`, and may take a long time when it is
+    /// close to that length. Use [`ScalarPrimitive::random_distinct_bounded`]
+    /// for tiny orders.
+    ///
+    /// ⚠️ WARNING: the collision checks are not constant-time. The sampled
+    /// scalars should be treated as public.
+    pub fn random_distinct(rng: &mut impl CryptoRngCore, out: &mut [Self]) {
+        for i in 0..out.len() {
+            let (prev, rest) = out.split_at_mut(i);
+
+            rest[0] = loop {
+                let scalar = Self::random(rng);
+
+                if Self::is_distinct_nonzero(prev, &scalar) {
+                    break scalar;
+                }
+            };
+        }
+    }
+
+    /// Fill `out` with random, pairwise distinct, non-zero scalars, drawing at
+    /// most `max_tries` samples in total.
+    ///
+    /// Returns `None` if `out` couldn't be filled within `max_tries`
+    /// samples, in which case its contents are unspecified.
+    ///
+    /// ⚠️ WARNING: the collision checks are not constant-time. The sampled
+    /// scalars should be treated as public.
+    pub fn random_distinct_bounded(
+        rng: &mut impl CryptoRngCore,
+        out: &mut [Self],
+        max_tries: u32,
+    ) -> Option<()> {
+        let mut tries = 0..max_tries;
+
+        for i in 0..out.len() {
+            let (prev, rest) = out.split_at_mut(i);
+
+            rest[0] = tries
+                .by_ref()
+                .map(|_| Self::random(rng))
+                .find(|scalar| Self::is_distinct_nonzero(prev, scalar))?;
+        }
+
+        Some(())
+    }
+
+    /// Generate a random scalar which is coprime to the given public
+    /// `modulus`, i.e. `gcd(scalar, modulus) = 1`, by rejection sampling.
+    ///
+    /// For prime orders and a `modulus` without small factors, the first
+    /// sample is almost always accepted.
+    ///
+    /// The GCD check is constant-time with respect to each sample, and
+    /// variable-time only with respect to the public `modulus`. The number
+    /// of rejected samples is revealed, but they are independent of the
+    /// returned scalar, so it is suitable for use as a secret blinder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn random_coprime(rng: &mut impl CryptoRngCore, modulus: &C::Uint) -> Self {
+        assert!(!bool::from(modulus.is_zero()), "modulus must be non-zero");
+
+        // Remove the (public) factors of two from the modulus, which instead
+        // require the sample to be odd
+        let mut odd_modulus = *modulus;
+
+        while bool::from(odd_modulus.is_even()) {
+            odd_modulus >>= 1;
+        }
+
+        let needs_odd = modulus.is_even();
+
+        loop {
+            let scalar = Self::random(rng);
+            let gcd = uint_gcd_odd(scalar.inner, odd_modulus);
+            let is_coprime = gcd.ct_eq(&C::Uint::ONE) & (scalar.is_odd() | !needs_odd);
+
+            if bool::from(is_coprime) {
+                return scalar;
+            }
+        }
+    }
+
+    /// Create a new scalar from [`Curve::Uint`].
+    pub fn new(uint: C::Uint) -> CtOption<Self> {
+        CtOption::new(Self { inner: uint }, uint.ct_lt(&Self::MODULUS))
+    }
+
+    /// Create a new scalar from [`Curve::Uint`] without checking whether it
+    /// overflows the modulus, in a `const` context.
+    ///
+    /// This is the `const` counterpart of
+    /// [`FromUintUnchecked::from_uint_unchecked`]. Combined with the `const`
+    /// decoding functions of `crypto-bigint`, it allows embedding fixed
+    /// scalars such as test vectors as constants:
+    ///
+    /// ```ignore
+    /// const SCALAR: ScalarPrimitive<MyCurve> =
+    ///     ScalarPrimitive::new_unchecked(U256::from_be_slice(&SCALAR_BYTES));
+    /// ```
+    ///
+    /// ⚠️ WARNING: no range check is performed. Out-of-range values lead to
+    /// mathematically invalid results, so such constants should be checked at
+    /// runtime, e.g. with [`ScalarPrimitive::new`] in a test.
+    pub const fn new_unchecked(uint: C::Uint) -> Self {
+        Self { inner: uint }
+    }
+
+    /// Assert that this scalar is canonical, i.e. less than
+    /// [`ScalarPrimitive::MODULUS`], in debug builds.
+    ///
+    /// This is a no-op in release builds. It is intended for catching
+    /// out-of-range values created with [`ScalarPrimitive::new_unchecked`] or
+    /// [`FromUintUnchecked::from_uint_unchecked`] early, e.g. in tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the value overflows the modulus.
+    pub fn debug_assert_canonical(&self) {
+        debug_assert!(
+            bool::from(self.inner.ct_lt(&Self::MODULUS)),
+            "scalar is not canonical: value must be less than MODULUS"
+        );
+    }
+
+    /// Create a new scalar from an optional [`Curve::Uint`], e.g. the output
+    /// of a constant-time decoder, checking that it is in range.
+    ///
+    /// Returns none if either `ct` is none or its value overflows the modulus.
+    ///
+    /// The range check is always performed, even when `ct` is none, so this
+    /// composes with other [`CtOption`] combinators without introducing a
+    /// data-dependent branch:
+    ///
+    /// ```ignore
+    /// let scalar = ScalarPrimitive::validate_in_range(decode_uint(bytes))
+    ///     .and_then(|s| CtOption::new(s, !s.is_zero()));
+    /// ```
+    pub fn validate_in_range(ct: CtOption<C::Uint>) -> CtOption<Self> {
+        ct.and_then(Self::new)
+    }
+
+    /// Create a new scalar from [`Curve::Uint`], returning an error if it
+    /// overflows the scalar modulus.
+    ///
+    /// This is intended for validating non-secret inputs. Use
+    /// [`ScalarPrimitive::new`] in constant-time contexts.
+    pub fn checked_new(uint: C::Uint) -> Result<Self> {
+        Option::from(Self::new(uint)).ok_or(Error)
+    }
+
+    /// Create a new scalar from [`Curve::Uint`], reducing it modulo the
+    /// scalar modulus if necessary, in constant time.
+    ///
+    /// Also returns a [`Choice`] which is set iff the input was already in
+    /// range, i.e. no reduction was needed.
+    pub fn new_reduced_flagged(uint: C::Uint) -> (Self, Choice) {
+        let modulus = NonZero::new(Self::MODULUS).unwrap();
+        let scalar = Self {
+            inner: uint_rem(&uint, &modulus),
+        };
+        scalar.debug_assert_canonical();
+        (scalar, uint.ct_lt(&Self::MODULUS))
+    }
+
+    /// Create a new scalar from a [`Curve::Uint`] in the range `[0, 2n)`
+    /// by subtracting the modulus `n` at most once, in constant time.
+    ///
+    /// This is cheaper than a general modular reduction and intended for
+    /// lazy-reduction arithmetic, e.g. the result of an unreduced addition.
+    ///
+    /// The input MUST be less than `2 * MODULUS`, which is checked by a
+    /// debug assertion. Larger inputs produce an out-of-range scalar in
+    /// release builds.
+    pub fn conditional_reduce(uint: C::Uint) -> Self {
+        let inner = uint.checked_sub(&Self::MODULUS).unwrap_or(uint);
+        debug_assert!(
+            bool::from(inner.ct_lt(&Self::MODULUS)),
+            "input must be less than 2 * MODULUS"
+        );
+        Self { inner }
+    }
+
+    /// Create a [`ScalarPrimitive`] from a signed integer, reducing it
+    /// modulo the scalar modulus.
+    ///
+    /// Negative values map to `MODULUS - |n|` (reduced), so e.g. `-1` maps to
+    /// `MODULUS - 1`.
+    pub fn from_i128(n: i128) -> Self {
+        let magnitude = n
+            .unsigned_abs()
+            .to_be_bytes()
+            .iter()
+            .fold(Self::ZERO, |acc, &byte| acc.shl8_add(byte));
+
+        Self::conditional_select(&magnitude, &-magnitude, Choice::from(u8::from(n < 0)))
+    }
+
+    /// Create a [`ScalarPrimitive`] from a signed integer, reducing it
+    /// modulo the scalar modulus.
+    ///
+    /// See [`ScalarPrimitive::from_i128`].
+    pub fn from_i64(n: i64) -> Self {
+        Self::from_i128(n.into())
+    }
+
+    /// Decode [`ScalarPrimitive`] from big endian bytes.
+    pub fn from_be_bytes(bytes: FieldBytes<C>) -> CtOption<Self> {
+        Self::new(C::Uint::from_be_byte_array(bytes))
+    }
+
+    /// Decode [`ScalarPrimitive`] from a big endian byte slice.
+    pub fn from_be_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() == C::Uint::BYTES {
+            Option::from(Self::from_be_bytes(GenericArray::clone_from_slice(slice))).ok_or(Error)
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Decode [`ScalarPrimitive`] from a big endian byte slice of any length,
+    /// reducing it modulo the scalar modulus, and return none if the result
+    /// is zero.
+    ///
+    /// This is intended for deriving nonces and challenges from external
+    /// data, where a zero scalar must be rejected. The reduction and zero
+    /// check are constant-time, though the running time depends on the
+    /// length of `bytes`.
+    pub fn from_bytes_mod_order_nonzero(bytes: &[u8]) -> CtOption<Self> {
+        let ret = Self::reduce_be_slice_ct(bytes);
+        CtOption::new(ret, !ret.is_zero())
+    }
+
+    /// Decode [`ScalarPrimitive`] from a big endian byte slice of any length,
+    /// reducing it modulo the scalar modulus, in constant time.
+    ///
+    /// The input is absorbed one [`Curve::Uint`]-sized chunk at a time, each
+    /// step reducing the double-width value formed by the residue so far and
+    /// the next chunk with a limb-wise long division. The running time
+    /// depends only on the length of `slice` and never on its contents,
+    /// which makes this suitable for reducing secret data, e.g. in
+    /// hash-to-scalar constructions.
+    ///
+    /// By contrast, [`ScalarPrimitive::from_be_slice`] and
+    /// [`ScalarPrimitive::read_be`] reject out-of-range inputs with a
+    /// [`Result`], revealing whether the value overflowed the modulus.
+    pub fn reduce_be_slice_ct(slice: &[u8]) -> Self {
+        let reducer = WideReducer::<C>::new();
+        let (head, tail) = slice.split_at(slice.len() % C::Uint::BYTES);
+
+        let inner = iter::once(head)
+            .chain(tail.chunks_exact(C::Uint::BYTES))
+            .fold(C::Uint::ZERO, |acc, chunk| {
+                let mut bytes = FieldBytes::<C>::default();
+                bytes[(C::Uint::BYTES - chunk.len())..].copy_from_slice(chunk);
+                reducer.reduce_wide(&C::Uint::from_be_byte_array(bytes), &acc)
+            });
+
+        Self { inner }
+    }
+
+    /// Interpret big endian `bytes` encoding a base field element, e.g. an
+    /// affine `x`-coordinate, as an integer and reduce it modulo the scalar
+    /// order `n`, in constant time.
+    ///
+    /// This is the `r = x mod n` step of ECDSA. Unlike
+    /// [`ScalarPrimitive::from_be_bytes`], values which exceed the order are
+    /// reduced rather than rejected.
+    pub fn from_field_bytes_reduced(bytes: &FieldBytes<C>) -> Self {
+        Self::reduce_be_slice_ct(bytes)
+    }
+
+    /// Decode [`ScalarPrimitive`] from big endian bytes after masking them,
+    /// e.g. to implement "clamping" of secret scalars.
+    ///
+    /// Bits are numbered from the least significant bit of the big endian
+    /// integer. The lowest `clear_low` and highest `clear_high` bits are
+    /// cleared, then `set_bit` is set if given. For example, X25519-style
+    /// clamping of a 256-bit value corresponds to `(3, 1, Some(254))`.
+    ///
+    /// The masked value is then reduced modulo the scalar modulus, so unlike
+    /// [`ScalarPrimitive::from_be_bytes`] this never fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clear_low + clear_high` or `set_bit` exceed the number of
+    /// bits in [`FieldBytes`].
+    pub fn from_be_bytes_masked(
+        mut bytes: FieldBytes<C>,
+        clear_low: u32,
+        clear_high: u32,
+        set_bit: Option<u32>,
+    ) -> Self {
+        let bits = Self::BYTES * 8;
+        let (clear_low, clear_high) = (clear_low as usize, clear_high as usize);
+        assert!(clear_low + clear_high <= bits, "too many bits cleared");
+
+        let mut apply = |i: usize, value: bool| {
+            let byte = &mut bytes[Self::BYTES - 1 - i / 8];
+            let mask = 1 << (i % 8);
+
+            if value {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        };
+
+        for i in (0..clear_low).chain((bits - clear_high)..bits) {
+            apply(i, false);
+        }
+
+        if let Some(i) = set_bit {
+            assert!((i as usize) < bits, "bit index out of range");
+            apply(i as usize, true);
+        }
+
+        Self::new_reduced_flagged(C::Uint::from_be_byte_array(bytes)).0
+    }
+
+    /// Format this scalar for use with a Montgomery ladder, clearing its
+    /// lowest `clear_low_bits` bits and all bits above `set_high_bit`, then
+    /// setting bit `set_high_bit`.
+    ///
+    /// For example, X25519-style clamping of a 256-bit value corresponds to
+    /// `(3, 254)`. See [`ScalarPrimitive::from_be_bytes_masked`].
+    ///
+    /// ⚠️ WARNING: the masked value is reduced modulo the scalar modulus, so
+    /// if `set_high_bit` is not below the bit length of the modulus the
+    /// result may no longer have the intended bit pattern.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `set_high_bit` is not less than [`ScalarPrimitive::BITS`],
+    /// or if `clear_low_bits` exceeds `set_high_bit`.
+    pub fn format_for_ladder(&self, clear_low_bits: u32, set_high_bit: u32) -> Self {
+        let bits = Self::BYTES as u32 * 8;
+        assert!(set_high_bit < bits, "bit index out of range");
+        assert!(clear_low_bits <= set_high_bit, "too many bits cleared");
+
+        Self::from_be_bytes_masked(
+            self.to_be_bytes(),
+            clear_low_bits,
+            bits - 1 - set_high_bit,
+            Some(set_high_bit),
+        )
+    }
+
+    /// Decode a sequence of concatenated big endian scalars, each
+    /// [`ScalarPrimitive::BYTES`] long.
+    ///
+    /// Returns an error if the length of `data` isn't a multiple of
+    /// [`ScalarPrimitive::BYTES`] or any of the scalars overflows the modulus.
+    ///
+    /// When the `rayon` feature is enabled, batches of at least 1024 scalars
+    /// are decoded in parallel. Decoding a single scalar is too cheap for
+    /// parallelism to pay off on smaller batches, so these are always decoded
+    /// serially.
+    #[cfg(feature = "alloc")]
+    pub fn batch_from_be_slice(data: &[u8]) -> Result<Vec<Self>> {
+        if data.len() % Self::BYTES != 0 {
+            return Err(Error);
+        }
+
+        #[cfg(feature = "rayon")]
+        if data.len() >= PAR_BATCH_MIN * Self::BYTES {
+            return data
+                .par_chunks_exact(Self::BYTES)
+                .map(Self::from_be_slice)
+                .collect();
+        }
+
+        data.chunks_exact(Self::BYTES)
+            .map(Self::from_be_slice)
+            .collect()
+    }
+
+    /// Decode big endian bytes into this scalar in place, in constant time.
+    ///
+    /// Returns none and leaves `self` unchanged if the decoded value
+    /// overflows the modulus.
+    pub fn assign_from_be_bytes(&mut self, bytes: &FieldBytes<C>) -> CtOption<()> {
+        let uint = C::Uint::from_be_byte_array(bytes.clone());
+        let is_valid = uint.ct_lt(&Self::MODULUS);
+        self.inner.conditional_assign(&uint, is_valid);
+        CtOption::new((), is_valid)
+    }
+
+    /// Decode big endian `bytes` as a scalar and add it to this one, in
+    /// constant time.
+    ///
+    /// Returns none if the decoded value overflows the modulus.
+    pub fn add_be_bytes(&self, bytes: &FieldBytes<C>) -> CtOption<Self> {
+        Self::from_be_bytes(bytes.clone()).map(|rhs| *self + rhs)
+    }
+
+    /// Decode big endian `bytes` as a scalar and subtract it from this one,
+    /// in constant time.
+    ///
+    /// Returns none if the decoded value overflows the modulus.
+    pub fn sub_be_bytes(&self, bytes: &FieldBytes<C>) -> CtOption<Self> {
+        Self::from_be_bytes(bytes.clone()).map(|rhs| *self - rhs)
+    }
+
+    /// Decode [`ScalarPrimitive`] from the front of a big endian byte slice,
+    /// advancing `cursor` past the decoded bytes.
+    ///
+    /// This allows decoding a sequence of concatenated scalars:
+    ///
+    /// ```ignore
+    /// while !buf.is_empty() {
+    ///     scalars.push(ScalarPrimitive::read_be(&mut buf)?);
+    /// }
+    /// ```
+    ///
+    /// Returns an error and leaves `cursor` unchanged if it contains fewer
+    /// than [`ScalarPrimitive::BYTES`] bytes or the decoded scalar overflows
+    /// the modulus.
+    pub fn read_be(cursor: &mut &[u8]) -> Result<Self> {
+        if cursor.len() < Self::BYTES {
+            return Err(Error);
+        }
+
+        let (bytes, rest) = cursor.split_at(Self::BYTES);
+        let scalar = Self::from_be_slice(bytes)?;
+        *cursor = rest;
+        Ok(scalar)
+    }
+
+    /// Decode [`ScalarPrimitive`] from a DER-encoded ASN.1 `INTEGER`,
+    /// including its tag and length.
+    ///
+    /// Returns an error if the encoding is invalid or non-minimal, or the
+    /// value is negative or overflows the modulus.
+    #[cfg(feature = "der")]
+    pub fn from_der_integer(bytes: &[u8]) -> Result<Self> {
+        let bytes = UIntRef::from_der(bytes)?.as_bytes();
+
+        if bytes.len() > Self::BYTES {
+            return Err(Error);
+        }
+
+        let mut repr = FieldBytes::<C>::default();
+        repr[(Self::BYTES - bytes.len())..].copy_from_slice(bytes);
+        Option::from(Self::from_be_bytes(repr)).ok_or(Error)
+    }
+
+    /// Decode [`ScalarPrimitive`] from an array of bits in least significant
+    /// bit first order, as produced by [`ScalarPrimitive::to_bits_le`].
+    ///
+    /// Returns none if the decoded value overflows the modulus.
+    pub fn from_bits_le(bits: &GenericArray<Choice, Prod<FieldSize<C>, U8>>) -> CtOption<Self>
+    where
+        FieldSize<C>: Mul<U8>,
+        Prod<FieldSize<C>, U8>: ArrayLength<Choice>,
+    {
+        let uint = bits.iter().rev().fold(C::Uint::ZERO, |acc, bit| {
+            (acc << 1) | C::Uint::from(u64::from(bit.unwrap_u8()))
+        });
+
+        Self::new(uint)
+    }
+
+    /// Decode [`ScalarPrimitive`] from little endian bytes.
+    pub fn from_le_bytes(bytes: FieldBytes<C>) -> CtOption<Self> {
+        Self::new(C::Uint::from_le_byte_array(bytes))
+    }
+
+    /// Determine if the given little endian bytes are the canonical encoding
+    /// of a scalar, i.e. if they don't overflow the modulus, in constant time.
+    pub fn is_canonical_le(bytes: &FieldBytes<C>) -> Choice {
+        C::Uint::from_le_byte_array(bytes.clone()).ct_lt(&Self::MODULUS)
+    }
+
+    /// Decode [`ScalarPrimitive`] from a little endian byte slice.
+    pub fn from_le_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() == C::Uint::BYTES {
+            Option::from(Self::from_le_bytes(GenericArray::clone_from_slice(slice))).ok_or(Error)
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Decode [`ScalarPrimitive`] from `2 * BYTES` little endian bytes,
+    /// reducing them modulo the scalar modulus, in constant time.
+    ///
+    /// This mirrors `from_bytes_mod_order_wide` used by Ed25519 and
+    /// Ristretto implementations to map 64 uniformly random bytes to a
+    /// scalar with negligible bias.
+    pub fn from_bytes_mod_order_wide_le(bytes: &GenericArray<u8, Prod<FieldSize<C>, U2>>) -> Self
+    where
+        FieldSize<C>: Mul<U2>,
+        Prod<FieldSize<C>, U2>: ArrayLength<u8>,
+    {
+        bytes
+            .iter()
+            .rev()
+            .fold(Self::ZERO, |acc, &byte| acc.shl8_add(byte))
+    }
+
+    /// Decode [`ScalarPrimitive`] from 64-bit words in little endian order,
+    /// i.e. least significant word first.
+    ///
+    /// Any number of words is accepted, as long as the value fits in
+    /// [`ScalarPrimitive::BITS`] bits. Returns none if the value overflows
+    /// the modulus.
+    pub fn from_u64_digits_le(words: &[u64]) -> CtOption<Self> {
+        Self::from_u64_digits(words.iter().copied())
+    }
+
+    /// Decode [`ScalarPrimitive`] from 64-bit words in big endian order,
+    /// i.e. most significant word first.
+    ///
+    /// Any number of words is accepted, as long as the value fits in
+    /// [`ScalarPrimitive::BITS`] bits. Returns none if the value overflows
+    /// the modulus.
+    pub fn from_u64_digits_be(words: &[u64]) -> CtOption<Self> {
+        Self::from_u64_digits(words.iter().rev().copied())
+    }
+
+    /// Decode [`ScalarPrimitive`] from exactly `BYTES / 4` 32-bit words in
+    /// little endian order, as produced by
+    /// [`ScalarPrimitive::to_u32_words_le_into`].
+    ///
+    /// Returns an error if the number of words is wrong or the value
+    /// overflows the modulus.
+    pub fn from_u32_words_le(words: &[u32]) -> Result<Self> {
+        if words.len() != Self::BYTES / 4 {
+            return Err(Error);
+        }
+
+        let mut bytes = FieldBytes::<C>::default();
+
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        Option::from(Self::from_le_bytes(bytes)).ok_or(Error)
+    }
+
+    /// Borrow the inner `C::Uint`.
+    pub fn as_uint(&self) -> &C::Uint {
+        &self.inner
+    }
+
+    /// Borrow the inner limbs as a slice.
+    pub fn as_limbs(&self) -> &[Limb] {
+        self.inner.as_ref()
+    }
+
+    /// Borrow the inner [`Curve::Uint`] mutably through a guard which
+    /// reduces it modulo the scalar modulus when dropped.
+    ///
+    /// This allows performing arbitrary raw integer operations in place
+    /// while still guaranteeing the scalar is in range afterwards:
+    ///
+    /// ```ignore
+    /// let mut guard = scalar.edit();
+    /// *guard = (*guard << 1) | C::Uint::ONE;
+    /// drop(guard); // `scalar` is now reduced
+    /// ```
+    pub fn edit(&mut self) -> ScalarEditGuard<'_, C> {
+        ScalarEditGuard { scalar: self }
+    }
+
+    /// Is this [`ScalarPrimitive`] value equal to zero?
+    pub fn is_zero(&self) -> Choice {
+        self.inner.is_zero()
+    }
+
+    /// Is this [`ScalarPrimitive`] value equal to one?
+    pub fn is_one(&self) -> Choice {
+        self.ct_eq_const(&Self::ONE)
+    }
+
+    /// Is this [`ScalarPrimitive`] value equal to `MODULUS - 1`, i.e. `-1`?
+    pub fn is_modulus_minus_one(&self) -> Choice {
+        self.ct_eq_const(&-Self::ONE)
+    }
+
+    /// Compare this (possibly secret) scalar with a public constant.
+    ///
+    /// This is equivalent to [`ConstantTimeEq::ct_eq`]: every limb is
+    /// compared regardless of the values involved, so the comparison leaks
+    /// nothing about `self` through timing, even though `konst` is public.
+    pub fn ct_eq_const(&self, konst: &Self) -> Choice {
+        self.ct_eq(konst)
+    }
+
+    /// Check whether this scalar equals `other` in constant time, e.g. when
+    /// verifying a received authentication tag against a computed one.
+    ///
+    /// Every limb is compared without short-circuiting, and the result is a
+    /// [`Choice`] rather than a [`bool`] so it can be combined with other
+    /// checks before branching. Use this rather than [`PartialEq`] for any
+    /// comparison involving secret values.
+    pub fn verify_eq(&self, other: &Self) -> Choice {
+        self.ct_eq(other)
+    }
+
+    /// Check whether this scalar is equal to `other` up to sign, i.e. whether
+    /// `self == other` or `self == -other`, in constant time.
+    pub fn ct_eq_abs(&self, other: &Self) -> Choice {
+        self.ct_eq(other) | self.ct_eq(&-other)
+    }
+
+    /// Compute the complement of this scalar relative to the order, i.e.
+    /// `(n - 1) - self`, in constant time.
+    ///
+    /// This reflects the range `[0, n)` onto itself, mapping zero to `n - 1`
+    /// and vice versa. Note that this differs from negation, which computes
+    /// `n - self` and maps zero to itself.
+    pub fn complement(&self) -> Self {
+        -*self - Self::ONE
+    }
+
+    /// Normalize this scalar to its "low" form, i.e. negate it if
+    /// [`IsHigh::is_high`], in constant time.
+    ///
+    /// This is the low-S normalization of ECDSA signatures, which ensures
+    /// `s <= n / 2`.
+    pub fn normalize_low_s(&self) -> Self {
+        self.normalize_low_s_flagged().0
+    }
+
+    /// Normalize this scalar to its "low" form like
+    /// [`ScalarPrimitive::normalize_low_s`], returning whether it was
+    /// negated.
+    pub fn normalize_low_s_flagged(&self) -> (Self, Choice) {
+        let was_normalized = self.is_high();
+        (
+            Self::conditional_select(self, &-self, was_normalized),
+            was_normalized,
+        )
+    }
+
+    /// Compare this scalar with `other` under their signed interpretation.
+    ///
+    /// Scalars above `n / 2` (i.e. those which are [`IsHigh::is_high`]) are
+    /// interpreted as the negative integers `x - n`, so every scalar is
+    /// mapped to a representative in `(-n/2, n/2]`, and these are compared
+    /// as signed integers. This differs from the unsigned [`Ord`] impl, e.g.
+    /// `-1` compares as less than `0`.
+    ///
+    /// ⚠️ WARNING: this is not constant-time.
+    pub fn signed_cmp(&self, other: &Self) -> Ordering {
+        let key = |x: &Self| (!bool::from(x.is_high()), x.inner);
+        key(self).cmp(&key(other))
+    }
+
+    /// Is this [`ScalarPrimitive`] value even?
+    pub fn is_even(&self) -> Choice {
+        self.inner.is_even()
+    }
+
+    /// Is this [`ScalarPrimitive`] value odd?
+    pub fn is_odd(&self) -> Choice {
+        self.inner.is_odd()
+    }
+
+    /// Get the least significant bit of this scalar as `0` or `1`.
+    ///
+    /// ⚠️ WARNING: this is intended for public scalars, e.g. when packing
+    /// flags into a compact encoding. Use [`ScalarPrimitive::ct_parity`]
+    /// for secret values.
+    pub fn parity(&self) -> u8 {
+        self.ct_parity().unwrap_u8()
+    }
+
+    /// Get the least significant bit of this scalar in constant time.
+    pub fn ct_parity(&self) -> Choice {
+        self.is_odd()
+    }
+
+    /// Get the most significant bit of this scalar's fixed-width encoding,
+    /// i.e. bit [`ScalarPrimitive::BITS`]` - 1`, in constant time.
+    pub fn msb(&self) -> Choice {
+        self.bit(Self::BITS - 1)
+    }
+
+    /// Is this scalar less than or equal to the given `bound`, in constant
+    /// time?
+    ///
+    /// The bound is an arbitrary integer rather than a scalar, so it may
+    /// exceed the modulus, in which case the result is always true.
+    pub fn ct_le_uint(&self, bound: &C::Uint) -> Choice {
+        !self.inner.ct_gt(bound)
+    }
+
+    /// Is this scalar less than `floor(numerator * n / denominator)` for the
+    /// scalar modulus `n`?
+    ///
+    /// This generalizes [`IsHigh`] to arbitrary fractions of the modulus.
+    /// The comparison is constant-time, while `numerator` and `denominator`
+    /// are treated as public. The threshold is computed exactly as
+    /// `numerator * q + floor(numerator * r / denominator)`, where `q` and
+    /// `r` are the quotient and remainder of `n / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn ct_lt_order_fraction(&self, numerator: u32, denominator: u32) -> Choice {
+        assert!(denominator != 0, "denominator must be non-zero");
+
+        // All scalars are less than `n`
+        if numerator >= denominator {
+            return Choice::from(1);
+        }
+
+        let den = NonZero::new(C::Uint::from(u64::from(denominator))).unwrap();
+        let num = C::Uint::from(u64::from(numerator));
+        let q = Self::MODULUS / den;
+        let r = uint_rem(&Self::MODULUS, &den);
+
+        // `r < denominator`, so `numerator * r` can't overflow
+        let frac = num.checked_mul(&r).unwrap() / den;
+        let threshold = q
+            .checked_mul(&num)
+            .and_then(|t| t.checked_add(&frac))
+            .unwrap();
+
+        self.inner.ct_lt(&threshold)
+    }
+
+    /// Is this [`ScalarPrimitive`] value in the inclusive range `[lo, hi]`?
+    ///
+    /// Computed in constant time. Always false if `lo > hi`.
+    pub fn ct_in_range(&self, lo: &Self, hi: &Self) -> Choice {
+        !self.ct_lt(lo) & !self.ct_gt(hi)
+    }
+
+    /// Select the entry of `table` at the (possibly secret) `index` in
+    /// constant time.
+    ///
+    /// Every entry is read and conditionally selected, so the memory access
+    /// pattern doesn't depend on `index`. Returns
+    /// [`ScalarPrimitive::ZERO`] if `index` is out of bounds.
+    pub fn ct_select<const N: usize>(table: &[Self; N], index: u8) -> Self {
+        let index = u64::from(index);
+
+        table
+            .iter()
+            .enumerate()
+            .fold(Self::ZERO, |acc, (i, entry)| {
+                Self::conditional_select(&acc, entry, (i as u64).ct_eq(&index))
+            })
+    }
+
+    /// Clear all bits of this scalar at or above position `bits`, i.e.
+    /// reduce it modulo `2^bits`.
+    ///
+    /// The result is less than `self`, so it is always a valid scalar. The
+    /// bits are cleared with a constant-time mask, although `bits` itself
+    /// is not treated as secret.
+    pub fn truncate_bits(&self, bits: u32) -> Self {
+        let bits = bits as usize;
+
+        if bits >= Self::BITS {
+            return *self;
+        }
+
+        let mask = C::Uint::MAX ^ (C::Uint::MAX << bits);
+        Self {
+            inner: self.inner & mask,
+        }
+    }
+
+    /// Encode [`ScalarPrimitive`] as big endian bytes.
+    pub fn to_be_bytes(&self) -> FieldBytes<C> {
+        self.inner.to_be_byte_array()
+    }
+
+    /// Encode [`ScalarPrimitive`] as little endian bytes.
+    pub fn to_le_bytes(&self) -> FieldBytes<C> {
+        self.inner.to_le_byte_array()
+    }
+
+    /// Encode [`ScalarPrimitive`] as the byte-reversed big endian encoding,
+    /// i.e. the opposite of the natural big endian byte order.
+    ///
+    /// This is always identical to [`ScalarPrimitive::to_le_bytes`], and is
+    /// intended to make the relationship explicit in protocols which mix
+    /// byte orders. Use [`ScalarPrimitive::from_reversed_bytes`] to decode.
+    pub fn to_opposite_endian_bytes(&self) -> FieldBytes<C> {
+        let mut bytes = self.to_be_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Decode [`ScalarPrimitive`] from the byte-reversed big endian encoding
+    /// produced by [`ScalarPrimitive::to_opposite_endian_bytes`].
+    ///
+    /// This is equivalent to [`ScalarPrimitive::from_le_bytes`]. Returns none
+    /// if the decoded value overflows the modulus.
+    pub fn from_reversed_bytes(bytes: &FieldBytes<C>) -> CtOption<Self> {
+        let mut bytes = bytes.clone();
+        bytes.reverse();
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Append a framed encoding of this scalar to a Fiat-Shamir transcript
+    /// `out`.
+    ///
+    /// The encoding consists of `label`, followed by the length
+    /// [`ScalarPrimitive::BYTES`] as a 32-bit little endian integer (as in
+    /// Merlin transcripts), followed by the big endian bytes of the scalar.
+    #[cfg(feature = "alloc")]
+    pub fn to_transcript_bytes(&self, label: &[u8], out: &mut Vec<u8>) {
+        out.reserve(label.len() + 4 + Self::BYTES);
+        out.extend_from_slice(label);
+        out.extend_from_slice(&(Self::BYTES as u32).to_le_bytes());
+        out.extend_from_slice(&self.to_be_bytes());
+    }
+
+    /// Encode [`ScalarPrimitive`] as big endian bytes, left-padded with zeros
+    /// to `N` bytes.
+    ///
+    /// This is useful for APIs which expect fixed-width elements regardless
+    /// of the curve, e.g. 32-byte Merkle tree leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than [`ScalarPrimitive::BYTES`].
+    pub fn to_be_bytes_padded_to<const N: usize>(&self) -> [u8; N] {
+        assert!(N >= Self::BYTES, "padded length is less than scalar size");
+        let mut ret = [0u8; N];
+        ret[(N - Self::BYTES)..].copy_from_slice(&self.to_be_bytes());
+        ret
+    }
+
+    /// Encode [`ScalarPrimitive`] as `ceil(BITS / 64)` 64-bit words in little
+    /// endian order, i.e. least significant word first.
+    #[cfg(feature = "alloc")]
+    pub fn to_u64_digits_le(&self) -> Vec<u64> {
+        self.to_le_bytes()
+            .chunks(8)
+            .map(|chunk| {
+                let mut word = [0u8; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(word)
+            })
+            .collect()
+    }
+
+    /// Encode [`ScalarPrimitive`] as `ceil(BITS / 64)` 64-bit words in big
+    /// endian order, i.e. most significant word first.
+    #[cfg(feature = "alloc")]
+    pub fn to_u64_digits_be(&self) -> Vec<u64> {
+        let mut words = self.to_u64_digits_le();
+        words.reverse();
+        words
+    }
+
+    /// Write this scalar into `out` as `BYTES / 4` 32-bit words in little
+    /// endian order, i.e. least significant word first, as commonly expected
+    /// by GPU kernels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len()` is not `BYTES / 4`.
+    pub fn to_u32_words_le_into(&self, out: &mut [u32]) {
+        assert_eq!(out.len(), Self::BYTES / 4, "invalid output length");
+
+        for (word, chunk) in out.iter_mut().zip(self.to_le_bytes().chunks_exact(4)) {
+            *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+    }
+
+    /// Encode this scalar as `BYTES / 4` 32-bit words in little endian
+    /// order. See [`ScalarPrimitive::to_u32_words_le_into`].
+    #[cfg(feature = "alloc")]
+    pub fn to_u32_words_le(&self) -> Vec<u32> {
+        let mut words = vec![0; Self::BYTES / 4];
+        self.to_u32_words_le_into(&mut words);
+        words
+    }
+
+    /// Get the bits of this scalar as a fixed-size array, in least significant
+    /// bit first order, i.e. `bits[0]` is the least significant bit.
+    ///
+    /// The array has exactly [`ScalarPrimitive::BITS`] elements.
+    pub fn to_bits_le(&self) -> GenericArray<Choice, Prod<FieldSize<C>, U8>>
+    where
+        FieldSize<C>: Mul<U8>,
+        Prod<FieldSize<C>, U8>: ArrayLength<Choice>,
+    {
+        GenericArray::generate(|i| self.bit(i))
+    }
+
+    /// Get the number of bits needed to represent any scalar, i.e.
+    /// `ceil(log2(n))` for the scalar modulus `n`.
+    ///
+    /// This is the number of bits written by
+    /// [`ScalarPrimitive::write_bits_le`].
+    pub fn bits_needed() -> usize {
+        uint_bits(&Self::MODULUS.checked_sub(&C::Uint::ONE).unwrap())
+    }
+
+    /// Count the number of set bits in this scalar.
+    ///
+    /// ⚠️ WARNING: this is not constant-time.
+    pub fn hamming_weight(&self) -> u32 {
+        self.as_limbs().iter().map(|limb| limb.0.count_ones()).sum()
+    }
+
+    /// Count the number of nonzero digits in the width-`width` non-adjacent
+    /// form (wNAF) of this scalar.
+    ///
+    /// ⚠️ WARNING: this is not constant-time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not in the range `2..=32`.
+    pub fn wnaf_weight(&self, width: u32) -> u32 {
+        assert!((2..=32).contains(&width), "width must be in 2..=32");
+
+        let width = width as usize;
+        let window_of = |pos: usize| {
+            (0..width)
+                .filter(|j| pos + j < Self::BITS)
+                .fold(0u64, |acc, j| {
+                    acc | (u64::from(self.bit(pos + j).unwrap_u8()) << j)
+                })
+        };
+
+        let mut weight = 0;
+        let mut carry = 0;
+        let mut pos = 0;
+
+        while pos < Self::BITS {
+            let window = carry + window_of(pos);
+
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
+
+            carry = u64::from(window >= 1 << (width - 1));
+            weight += 1;
+            pos += width;
+        }
+
+        weight + carry as u32
+    }
+
+    /// Fold over the bits of this scalar in least significant bit first order,
+    /// i.e. compute `f(... f(f(init, bit_0), bit_1) ..., bit_{BITS-1})`.
+    ///
+    /// All [`ScalarPrimitive::BITS`] bits are visited regardless of the value
+    /// of the scalar. This is only constant-time if `f` is as well, i.e. it
+    /// must not branch on or otherwise leak the [`Choice`] it is given.
+    pub fn fold_bits_le<T>(&self, init: T, mut f: impl FnMut(T, Choice) -> T) -> T {
+        (0..Self::BITS).fold(init, |acc, i| f(acc, self.bit(i)))
+    }
+
+    /// Encode this scalar as an ASN.1 `INTEGER`, using `buf` as backing
+    /// storage for the returned [`UIntRef`].
+    ///
+    /// The DER encoding of the result is the minimal big endian encoding of
+    /// the scalar, prefixed with a zero byte when the high bit is set.
+    #[cfg(feature = "der")]
+    pub fn to_der_integer<'a>(&self, buf: &'a mut FieldBytes<C>) -> der::Result<UIntRef<'a>> {
+        *buf = self.to_be_bytes();
+        UIntRef::new(buf)
+    }
+
+    /// Write the little endian (i.e. base-256 digit) encoding of this scalar
+    /// into `out`, which may be of any length.
+    ///
+    /// If `out` is longer than [`ScalarPrimitive::BYTES`] the remaining
+    /// bytes are zero-padded. If it is shorter, the scalar is written as long
+    /// as the truncated bytes are all zero.
+    ///
+    /// Returns an error and leaves `out` unchanged if it is too short to hold
+    /// the minimal representation of the scalar. Note that this leaks whether
+    /// the scalar fits into `out` through timing.
+    pub fn to_le_digits_padded(&self, out: &mut [u8]) -> Result<()> {
+        let bytes = self.to_le_bytes();
+        let len = out.len().min(bytes.len());
+
+        if bytes[len..].iter().any(|&b| b != 0) {
+            return Err(Error);
+        }
+
+        out[..len].copy_from_slice(&bytes[..len]);
+        out[len..].fill(0);
+        Ok(())
+    }
+
+    /// Get the Montgomery form of this scalar, i.e. `self * R mod n`, as
+    /// little endian 64-bit words.
+    ///
+    /// The scalar is encoded in `ceil(BYTES / 8)` words, and the Montgomery
+    /// constant is `R = 2^(64 * words) mod n`, which matches the convention
+    /// of most backends using 64-bit limbs. If [`ScalarPrimitive::BYTES`] is
+    /// a multiple of 8 this is `R = 2^BITS mod n`.
+    pub fn to_montgomery_words(&self) -> GenericArray<u64, Quot<Sum<FieldSize<C>, U7>, U8>>
+    where
+        FieldSize<C>: Add<U7>,
+        Sum<FieldSize<C>, U7>: Div<U8>,
+        Quot<Sum<FieldSize<C>, U7>, U8>: ArrayLength<u64>,
+    {
+        let mut words = GenericArray::<u64, _>::default();
+
+        // `self * 2^(64 * words)` is reduced by appending zero limbs
+        let shift = iter::repeat(Limb::ZERO).take(words.len() * 64 / Limb::BITS);
+        let mont = Self {
+            inner: WideReducer::<C>::new()
+                .reduce_limbs(self.inner.as_ref().iter().rev().copied().chain(shift)),
+        };
+        let bytes = mont.to_le_bytes();
+
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks(8)) {
+            *word = chunk
+                .iter()
+                .rev()
+                .fold(0, |acc, &byte| (acc << 8) | u64::from(byte));
+        }
+
+        words
+    }
+
+    /// Decode a scalar from its Montgomery form, given as little endian
+    /// 64-bit words, as produced by [`ScalarPrimitive::to_montgomery_words`].
+    ///
+    /// Returns none if the words encode a value which overflows the modulus,
+    /// or if the modulus is even, in which case `R` has no inverse.
+    pub fn from_montgomery_words(
+        words: &GenericArray<u64, Quot<Sum<FieldSize<C>, U7>, U8>>,
+    ) -> CtOption<Self>
+    where
+        FieldSize<C>: Add<U7>,
+        Sum<FieldSize<C>, U7>: Div<U8>,
+        Quot<Sum<FieldSize<C>, U7>, U8>: ArrayLength<u64>,
+    {
+        let mut bytes = FieldBytes::<C>::default();
+        let mut padding = 0;
+
+        for (chunk, word) in bytes.chunks_mut(8).zip(words) {
+            let word = word.to_le_bytes();
+            let (value, pad) = word.split_at(chunk.len());
+            chunk.copy_from_slice(value);
+            padding = pad.iter().fold(padding, |acc, &byte| acc | byte);
+        }
+
+        // Dividing by `R = 2^(64 * words)` is a Montgomery reduction
+        let rounds = words.len() * 64 / Limb::BITS;
+        let is_valid = padding.ct_eq(&0) & C::ORDER.is_odd();
+
+        Self::from_le_bytes(bytes).and_then(|mont| {
+            let inner = wide::montgomery_reduce::<C>(&mont.inner, rounds);
+            CtOption::new(Self { inner }, is_valid)
+        })
+    }
+
+    /// Decode a scalar from unpadded Base64url, as used for the `d`
+    /// parameter of a JWK elliptic curve private key.
+    ///
+    /// The encoding must decode to exactly [`ScalarPrimitive::BYTES`] bytes
+    /// (i.e. leading zeros must not be stripped), as mandated by JOSE, and
+    /// represent a value less than the modulus.
+    #[cfg(feature = "jwk")]
+    pub fn from_base64url(s: &str) -> Result<Self> {
+        let mut bytes = FieldBytes::<C>::default();
+
+        if Base64Url::decode(s, &mut bytes).map_err(|_| Error)?.len() != Self::BYTES {
+            return Err(Error);
+        }
+
+        Option::from(Self::from_be_bytes(bytes)).ok_or(Error)
+    }
+
+    /// Encode this scalar as fixed-width big endian bytes in unpadded
+    /// Base64url, as used for the `d` parameter of a JWK elliptic curve
+    /// private key.
+    #[cfg(feature = "jwk")]
+    pub fn to_base64url(&self) -> String {
+        Base64Url::encode_string(&self.to_be_bytes())
+    }
+
+    /// Parse a scalar from a big endian hexadecimal string, like the
+    /// [`FromStr`][`str::FromStr`] impl but reporting the cause of failure.
+    ///
+    /// Both upper and lower case digits are accepted. The string must encode
+    /// exactly [`ScalarPrimitive::BYTES`] bytes.
+    pub fn from_hex_detailed(hex: &str) -> core::result::Result<Self, ScalarParseError> {
+        if hex.len() != Self::BYTES * 2 {
+            return Err(ScalarParseError::WrongLength);
+        }
+
+        let mut bytes = FieldBytes::<C>::default();
+        base16ct::mixed::decode(hex, &mut bytes).map_err(|_| ScalarParseError::InvalidHex)?;
+        Option::from(Self::from_be_bytes(bytes)).ok_or(ScalarParseError::OutOfRange)
+    }
+
+    /// Parse a scalar from a big endian hexadecimal string of exactly
+    /// `2 * BYTES` characters.
+    ///
+    /// Shorter inputs are rejected rather than zero-padded, so a truncated
+    /// string can't silently decode to a different scalar. Use
+    /// [`ScalarPrimitive::from_hex_detailed`] to find out why parsing failed.
+    pub fn from_be_hex_fixed(hex: &str) -> Result<Self> {
+        Self::from_hex_detailed(hex).map_err(Error::from)
+    }
+
+    /// Encode this scalar as big endian lower case hexadecimal into a
+    /// stack-allocated array of ASCII characters.
+    ///
+    /// This produces the same output as the [`LowerHex`][`fmt::LowerHex`]
+    /// impl, without requiring a formatter or allocation.
+    pub fn to_lower_hex_array(&self) -> GenericArray<u8, Prod<FieldSize<C>, U2>>
+    where
+        FieldSize<C>: Mul<U2>,
+        Prod<FieldSize<C>, U2>: ArrayLength<u8>,
+    {
+        let mut ret = GenericArray::default();
+        base16ct::lower::encode(&self.to_be_bytes(), &mut ret).expect("buffer length is correct");
+        ret
+    }
+
+    /// Encode this scalar as big endian upper case hexadecimal into a
+    /// stack-allocated array of ASCII characters.
+    ///
+    /// This produces the same output as the [`UpperHex`][`fmt::UpperHex`]
+    /// impl, without requiring a formatter or allocation.
+    pub fn to_upper_hex_array(&self) -> GenericArray<u8, Prod<FieldSize<C>, U2>>
+    where
+        FieldSize<C>: Mul<U2>,
+        Prod<FieldSize<C>, U2>: ArrayLength<u8>,
+    {
+        let mut ret = GenericArray::default();
+        base16ct::upper::encode(&self.to_be_bytes(), &mut ret).expect("buffer length is correct");
+        ret
+    }
+
+    /// Parse a scalar from a string of digits in the given `radix`, following
+    /// the conventions of [`u128::from_str_radix`], i.e. an optional leading
+    /// `+` followed by digits `0-9` and `a-z` (case-insensitive).
+    ///
+    /// Returns an error if `radix` is not in the range `2..=36`, there are no
+    /// digits, the string contains invalid digits, or the value overflows the
+    /// modulus.
+    ///
+    /// ⚠️ WARNING: this is not constant-time.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self> {
+        let s = s.strip_prefix('+').unwrap_or(s);
+
+        if !(2..=36).contains(&radix) || s.is_empty() {
+            return Err(Error);
+        }
+
+        let radix_uint = C::Uint::from(u64::from(radix));
+        let uint = s.chars().try_fold(C::Uint::ZERO, |acc, c| {
+            let digit = C::Uint::from(u64::from(c.to_digit(radix)?));
+            Option::from(
+                acc.checked_mul(&radix_uint)
+                    .and_then(|acc| acc.checked_add(&digit)),
+            )
+        });
+
+        Self::checked_new(uint.ok_or(Error)?)
+    }
+
+    /// Format this scalar as a string of digits in the given `radix`, using
+    /// `0-9` and `a-z` for digits, without leading zeros.
+    ///
+    /// ⚠️ WARNING: this is not constant-time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    #[cfg(feature = "alloc")]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+        let divisor = NonZero::new(C::Uint::from(u64::from(radix))).unwrap();
+        let mut uint = self.inner;
+        let mut digits = Vec::new();
+
+        loop {
+            let digit = uint_rem(&uint, &divisor).as_ref()[0].0 as u32;
+            digits.push(char::from_digit(digit, radix).expect("digit < radix"));
+            uint = uint / divisor;
+
+            if bool::from(uint.is_zero()) {
+                break;
+            }
+        }
+
+        digits.iter().rev().collect()
+    }
+
+    /// Convert to a `C::Uint`.
+    pub fn to_uint(&self) -> C::Uint {
+        self.inner
+    }
+
+    /// Consume this scalar, returning the inner `C::Uint`.
+    ///
+    /// This complements [`ScalarPrimitive::as_uint`] and
+    /// [`ScalarPrimitive::to_uint`] in code which transfers ownership:
+    ///
+    /// ```ignore
+    /// let uint: U256 = ScalarPrimitive::<MyCurve>::ONE.into_inner();
+    /// assert_eq!(uint, U256::ONE);
+    /// ```
+    pub fn into_inner(self) -> C::Uint {
+        self.inner
+    }
+
+    /// Reinterpret this scalar as a scalar of the curve `D`, which uses the
+    /// same integer type, e.g. when the scalar field of one curve is the base
+    /// field of the other.
+    ///
+    /// Returns none if the value overflows the order of `D`.
+    pub fn map_into<D>(&self) -> CtOption<ScalarPrimitive<D>>
+    where
+        D: Curve<Uint = C::Uint>,
+    {
+        ScalarPrimitive::new(self.inner)
+    }
+
+    /// Convert this scalar into a scalar of the curve `D`, which uses the
+    /// same integer type and is expected to have the same order, e.g. when
+    /// `D` is a newtype wrapper around `C`.
+    ///
+    /// This is equivalent to [`ScalarPrimitive::map_into`], and likewise
+    /// returns none if the value overflows the order of `D`.
+    pub fn rebase<D>(self) -> CtOption<ScalarPrimitive<D>>
+    where
+        D: Curve<Uint = C::Uint>,
+    {
+        self.map_into()
+    }
+
+    /// Reduce this scalar modulo the given (smaller) `modulus`, e.g. the
+    /// order of a subgroup or a cofactor.
+    ///
+    /// The result is returned as a raw [`Curve::Uint`] rather than a
+    /// [`ScalarPrimitive`] since it is a residue modulo `modulus`, not an
+    /// element of the scalar field, and arithmetic on it as a scalar would
+    /// reduce by the wrong modulus.
+    pub fn reduce_mod(&self, modulus: &NonZero<C::Uint>) -> C::Uint {
+        uint_rem(&self.inner, modulus)
+    }
+
+    /// Reduce a double-width integer, e.g. the output of
+    /// [`Uint::mul_wide`][`crate::bigint::Uint::mul_wide`] after
+    /// concatenation, modulo [`ScalarPrimitive::MODULUS`].
+    pub fn reduce_wide_uint(wide: <C::Uint as Concat>::Output) -> Self
+    where
+        C::Uint: Concat,
+        <C::Uint as Concat>::Output: Integer + Split<Output = C::Uint>,
+    {
+        let modulus = NonZero::new(C::Uint::ZERO.concat(&Self::MODULUS)).unwrap();
+        let (hi, lo) = uint_rem(&wide, &modulus).split();
+        debug_assert!(bool::from(hi.is_zero()));
+        let ret = Self { inner: lo };
+        ret.debug_assert_canonical();
+        ret
+    }
+
+    /// Reduce a signed double-width integer, given as its `magnitude` and
+    /// sign, modulo [`ScalarPrimitive::MODULUS`].
+    ///
+    /// This computes `magnitude mod n`, negated if `negative` is set. The
+    /// negation is applied with a constant-time select.
+    pub fn reduce_signed_wide(magnitude: &<C::Uint as Concat>::Output, negative: bool) -> Self
+    where
+        C::Uint: Concat,
+        <C::Uint as Concat>::Output: Integer + Split<Output = C::Uint>,
+    {
+        let ret = Self::reduce_wide_uint(*magnitude);
+        Self::conditional_select(&ret, &-ret, Choice::from(u8::from(negative)))
+    }
+
+    /// Multiply this scalar by a small public integer `k` using
+    /// double-and-add, which is cheaper than a full multiplication for small
+    /// values of `k`.
+    ///
+    /// This is constant-time with respect to `self` but not `k`.
+    pub fn mul_small(&self, k: u32) -> Self {
+        let mut acc = Self::ZERO;
+
+        for i in (0..(u32::BITS - k.leading_zeros())).rev() {
+            acc += acc;
+
+            if (k >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+
+        acc
+    }
+
+    /// Multiply this scalar by two modulo the scalar modulus.
+    pub fn double(&self) -> Self {
+        *self + self
+    }
+
+    /// Multiply this scalar by `2^k` modulo the scalar modulus by doubling
+    /// it `k` times.
+    ///
+    /// This is constant-time with respect to `self` but not `k`. For small
+    /// values of `k` it is cheaper than multiplying by `2^k` as a scalar.
+    pub fn mul_2exp(&self, k: u32) -> Self {
+        (0..k).fold(*self, |acc, _| acc.double())
+    }
+
+    /// Divide this scalar by two modulo the scalar modulus, in constant time.
+    ///
+    /// The modulus must be odd, as is the case for any prime order curve.
+    pub fn halve(&self) -> Self {
+        // For odd `x`, `(x + n) / 2 = (x >> 1) + (n >> 1) + 1` since `n` is odd
+        let half_modulus = Self {
+            inner: Self::MODULUS >> 1,
+        } + Self::ONE;
+
+        let mut inner = self.inner;
+        inner >>= 1;
+
+        Self { inner } + Self::conditional_select(&Self::ZERO, &half_modulus, self.is_odd())
+    }
+
+    /// Divide this scalar by `2^k` modulo the scalar modulus by halving it
+    /// `k` times.
+    ///
+    /// This is constant-time with respect to `self` but not `k`. For small
+    /// values of `k` it is cheaper than multiplying by the inverse of `2^k`.
+    ///
+    /// The modulus must be odd, as is the case for any prime order curve.
+    pub fn halve_times(&self, k: u32) -> Self {
+        (0..k).fold(*self, |acc, _| acc.halve())
+    }
+
+    /// Compute `self * b + c`.
+    pub fn mul_add(&self, b: &Self, c: &Self) -> Self {
+        *self * b + c
+    }
+
+    /// Determine if `self * cofactor` is zero modulo the order, in constant
+    /// time.
+    ///
+    /// This can be used to implement small subgroup checks in the scalar
+    /// domain when the order is composite. Note that it is a necessary but
+    /// not sufficient condition for the corresponding point to lie in a small
+    /// subgroup, and does not replace a check on the point itself.
+    pub fn times_cofactor_is_zero(&self, cofactor: &C::Uint) -> Choice {
+        let modulus = NonZero::new(Self::MODULUS).unwrap();
+        let cofactor = Self {
+            inner: uint_rem(cofactor, &modulus),
+        };
+        (*self * cofactor).is_zero()
+    }
+
+    /// Compute the inner product `a[0] * b[0] + ... + a[n-1] * b[n-1]`.
+    ///
+    /// The unreduced double-width products are summed in a triple-width
+    /// accumulator which is reduced only once at the end, which avoids a
+    /// modular reduction per term.
+    ///
+    /// Returns none if `a` and `b` have different lengths.
+    pub fn dot_product(a: &[Self], b: &[Self]) -> CtOption<Self> {
+        if a.len() != b.len() {
+            return CtOption::new(Self::ZERO, Choice::from(0));
+        }
+
+        // The sum of fewer than `2^BITS` double-width products can't overflow
+        let (mut lo, mut mid, mut hi) = (C::Uint::ZERO, C::Uint::ZERO, C::Uint::ZERO);
+
+        for (x, y) in a.iter().zip(b) {
+            let (product_lo, product_hi) = wide::mul_wide(&x.inner, &y.inner);
+            let (sum, carry) = wide::adc(&lo, &product_lo, Limb::ZERO);
+            lo = sum;
+            let (sum, carry) = wide::adc(&mid, &product_hi, carry);
+            mid = sum;
+            hi = wide::adc(&hi, &C::Uint::ZERO, carry).0;
+        }
+
+        let limbs = [hi, mid, lo];
+        let ret = Self {
+            inner: WideReducer::<C>::new()
+                .reduce_limbs(limbs.iter().flat_map(|x| x.as_ref().iter().rev().copied())),
+        };
+        ret.debug_assert_canonical();
+        CtOption::new(ret, Choice::from(1))
+    }
+
+    /// Evaluate the polynomial with the given coefficients at `point` using
+    /// Horner's method.
+    ///
+    /// Coefficients are in ascending order, i.e. `coeffs[0]` is the constant
+    /// term, and the returned value is
+    /// `coeffs[n-1] * point^(n-1) + ... + coeffs[1] * point + coeffs[0]`.
+    ///
+    /// Returns [`ScalarPrimitive::ZERO`] if `coeffs` is empty.
+    pub fn horner_eval(point: &Self, coeffs: &[Self]) -> Self {
+        coeffs
+            .iter()
+            .rev()
+            .fold(Self::ZERO, |acc, coeff| acc.mul_add(point, coeff))
+    }
+
+    /// Evaluate the polynomial with the given coefficients at `point` using
+    /// Horner's method.
+    ///
+    /// Coefficients are in descending order, i.e. `coeffs[0]` is the
+    /// coefficient of the highest degree term and `coeffs[n-1]` is the
+    /// constant term.
+    ///
+    /// Returns [`ScalarPrimitive::ZERO`] if `coeffs` is empty.
+    pub fn horner_eval_descending(point: &Self, coeffs: &[Self]) -> Self {
+        coeffs
+            .iter()
+            .fold(Self::ZERO, |acc, coeff| acc.mul_add(point, coeff))
+    }
+
+    /// Compute the multiplicative inverse of this scalar.
+    ///
+    /// Uses a constant-time variant of the binary extended Euclidean
+    /// algorithm which always runs for `2 * C::Uint::BITS` iterations.
+    ///
+    /// Returns none if the scalar is zero (or, for curves with a composite
+    /// order, if it isn't coprime to the order).
+    pub fn invert(&self) -> CtOption<Self> {
+        let modulus = Self::MODULUS;
+
+        // `(n + 1) / 2` i.e. the inverse of 2, assuming the modulus is odd
+        let half_modulus = (modulus >> 1).add_mod(&C::Uint::ONE, &modulus);
+
+        let mut a = self.inner;
+        let mut b = modulus;
+        let mut u = C::Uint::ONE;
+        let mut v = C::Uint::ZERO;
+
+        // Invariants: `a = u * self (mod n)` and `b = v * self (mod n)`
+        for _ in 0..(2 * C::Uint::BITS) {
+            let a_is_odd = a.is_odd();
+            let swap = a_is_odd & a.ct_lt(&b);
+            C::Uint::conditional_swap(&mut a, &mut b, swap);
+            C::Uint::conditional_swap(&mut u, &mut v, swap);
+
+            a.conditional_assign(&a.checked_sub(&b).unwrap_or(a), a_is_odd);
+            u.conditional_assign(&u.sub_mod(&v, &modulus), a_is_odd);
+
+            let u_is_odd = u.is_odd();
+            a >>= 1;
+            u >>= 1;
+            u.conditional_assign(&u.add_mod(&half_modulus, &modulus), u_is_odd);
+        }
+
+        // `b` now holds `gcd(self, n)`
+        CtOption::new(Self { inner: v }, b.ct_eq(&C::Uint::ONE))
+    }
+
+    /// Compute the multiplicative inverse of this scalar using Stein's binary
+    /// GCD algorithm, which is considerably faster than
+    /// [`ScalarPrimitive::invert`].
+    ///
+    /// ⚠️ WARNING: this operation is variable time with respect to the
+    /// scalar. Use it only with public scalars, e.g. when verifying
+    /// signatures.
+    ///
+    /// The modulus must be odd. Returns none if the scalar is zero (or, for
+    /// curves with a composite order, if it isn't coprime to the order).
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let mut u = self.inner;
+        let mut v = Self::MODULUS;
+        let mut a = Self::ONE;
+        let mut c = Self::ZERO;
+
+        // Invariants: `u = a * self (mod n)` and `v = c * self (mod n)`
+        while !bool::from(u.is_zero()) {
+            while bool::from(u.is_even()) {
+                u >>= 1;
+                a = a.halve();
+            }
+
+            while bool::from(v.is_even()) {
+                v >>= 1;
+                c = c.halve();
+            }
+
+            if u < v {
+                v = v.checked_sub(&u).unwrap();
+                c -= a;
+            } else {
+                u = u.checked_sub(&v).unwrap();
+                a -= c;
+            }
+        }
+
+        // `v` now holds `gcd(self, n)`
+        CtOption::new(c, v.ct_eq(&C::Uint::ONE))
+    }
+
+    /// Compute the multiplicative inverse of this scalar, blinding the
+    /// operand of the inversion with a random non-zero scalar `b`.
+    ///
+    /// This computes `(self * b)^-1 * b` so that the inversion never operates
+    /// on `self` directly, which is a standard countermeasure against side
+    /// channel attacks on e.g. ECDSA nonce inversion.
+    ///
+    /// Returns none if the scalar is zero. The curve order must be prime.
+    pub fn invert_blinded(&self, rng: &mut impl CryptoRngCore) -> CtOption<Self> {
+        let blinder = loop {
+            let b = Self::random(rng);
+
+            if !bool::from(b.is_zero()) {
+                break b;
+            }
+        };
+
+        (*self * blinder).invert().map(|inv| inv * blinder)
+    }
+
+    /// Compute `self / rhs`, i.e. `self` multiplied by the inverse of `rhs`.
+    ///
+    /// Returns none if `rhs` is zero (or, for curves with a composite order,
+    /// if it isn't coprime to the order).
+    pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
+        rhs.invert().map(|inv| *self * inv)
+    }
+
+    /// Replace each of the given scalars in-place with the product of itself
+    /// and all preceding scalars, i.e. `[s0, s0 * s1, s0 * s1 * s2, ...]`.
+    pub fn cumulative_products_in_place(scalars: &mut [Self]) {
+        let mut acc = Self::ONE;
+
+        for scalar in scalars {
+            acc *= *scalar;
+            *scalar = acc;
+        }
+    }
+
+    /// Compute the prefix products `[s0, s0 * s1, s0 * s1 * s2, ...]` of the
+    /// given scalars.
+    ///
+    /// See [`ScalarPrimitive::cumulative_products_in_place`] for a variant
+    /// which doesn't allocate.
+    #[cfg(feature = "alloc")]
+    pub fn cumulative_products(scalars: &[Self]) -> Vec<Self> {
+        let mut ret = scalars.to_vec();
+        Self::cumulative_products_in_place(&mut ret);
+        ret
+    }
+
+    /// Compute the element-wise products `out[i] = a[i] * b[i]` of the given
+    /// scalars.
+    ///
+    /// Returns an error if the lengths of `a`, `b` and `out` don't all match.
+    pub fn batch_mul(a: &[Self], b: &[Self], out: &mut [Self]) -> Result<()> {
+        if a.len() != b.len() || a.len() != out.len() {
+            return Err(Error);
+        }
+
+        for ((out, a), b) in out.iter_mut().zip(a).zip(b) {
+            *out = *a * b;
+        }
+
+        Ok(())
+    }
+
+    /// Compute the element-wise products `a[i] * b[i]` of the given scalars.
+    ///
+    /// Returns an error if the lengths of `a` and `b` don't match. See
+    /// [`ScalarPrimitive::batch_mul`] for a variant which doesn't allocate.
+    #[cfg(feature = "alloc")]
+    pub fn batch_mul_vec(a: &[Self], b: &[Self]) -> Result<Vec<Self>> {
+        let mut ret = vec![Self::ZERO; a.len()];
+        Self::batch_mul(a, b, &mut ret)?;
+        Ok(ret)
+    }
+
+    /// Invert all of the given scalars in-place using Montgomery's trick,
+    /// which requires only a single inversion.
+    ///
+    /// The caller-provided `scratch` space avoids the need for heap
+    /// allocation. Its contents are overwritten.
+    ///
+    /// Returns none and leaves `scalars` unchanged if any of the scalars is
+    /// zero.
+    ///
+    /// # Panics
+    ///
+    /// If `scratch.len() != scalars.len()`.
+    pub fn batch_invert_with_scratch(scalars: &mut [Self], scratch: &mut [Self]) -> CtOption<()> {
+        assert_eq!(
+            scalars.len(),
+            scratch.len(),
+            "scratch space length must match the number of scalars"
+        );
+
+        let mut acc = Self::ONE;
+
+        for (scalar, prefix) in scalars.iter().zip(scratch.iter_mut()) {
+            *prefix = acc;
+            acc *= scalar;
+        }
+
+        let acc_inv = acc.invert();
+        let is_some = acc_inv.is_some();
+        let mut acc = acc_inv.unwrap_or(Self::ZERO);
+
+        for (scalar, prefix) in scalars.iter_mut().zip(scratch.iter()).rev() {
+            let inverse = acc * prefix;
+            acc *= *scalar;
+            scalar.conditional_assign(&inverse, is_some);
+        }
+
+        CtOption::new((), is_some)
+    }
+
+    /// Invert all of the given scalars in-place using Montgomery's trick,
+    /// which requires only a single inversion.
+    ///
+    /// Returns none and leaves `scalars` unchanged if any of the scalars is
+    /// zero.
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert(scalars: &mut [Self]) -> CtOption<()> {
+        let mut scratch = vec![Self::ZERO; scalars.len()];
+        Self::batch_invert_with_scratch(scalars, &mut scratch)
+    }
+
+    /// Invert all of the non-zero scalars in-place using Montgomery's trick,
+    /// leaving zeros as zero.
+    ///
+    /// Zeros are substituted with one in the running product, so which of
+    /// the scalars are zero isn't revealed by the running time, which
+    /// depends only on the number of scalars. The curve order must be prime:
+    /// otherwise, if any scalar isn't invertible, all of them are set to
+    /// zero.
+    ///
+    /// The caller-provided `scratch` space avoids the need for heap
+    /// allocation. Its contents are overwritten.
+    ///
+    /// # Panics
+    ///
+    /// If `scratch.len() != scalars.len()`.
+    pub fn batch_invert_or_zero_with_scratch(scalars: &mut [Self], scratch: &mut [Self]) {
+        assert_eq!(
+            scalars.len(),
+            scratch.len(),
+            "scratch space length must match the number of scalars"
+        );
+
+        let or_one = |scalar: &Self| Self::conditional_select(scalar, &Self::ONE, scalar.is_zero());
+        let mut acc = Self::ONE;
+
+        for (scalar, prefix) in scalars.iter().zip(scratch.iter_mut()) {
+            *prefix = acc;
+            acc *= or_one(scalar);
+        }
+
+        let mut acc = acc.invert().unwrap_or(Self::ZERO);
+
+        for (scalar, prefix) in scalars.iter_mut().zip(scratch.iter()).rev() {
+            let inverse = acc * prefix;
+            acc *= or_one(scalar);
+            *scalar = Self::conditional_select(&inverse, &Self::ZERO, scalar.is_zero());
+        }
+    }
+
+    /// Invert all of the non-zero scalars in-place using Montgomery's trick,
+    /// leaving zeros as zero.
+    ///
+    /// See [`ScalarPrimitive::batch_invert_or_zero_with_scratch`] for
+    /// details.
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert_or_zero(scalars: &mut [Self]) {
+        let mut scratch = vec![Self::ZERO; scalars.len()];
+        Self::batch_invert_or_zero_with_scratch(scalars, &mut scratch)
+    }
+
+    /// Raise this scalar to the power of `exp`, given as little endian
+    /// 64-bit words, in constant time.
+    ///
+    /// The running time depends only on the length of `exp`.
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut acc = Self::ONE;
 
-#[cfg(feature = "serde")]
-use serdect::serde::{de, ser, Deserialize, Serialize};
+        for word in exp.as_ref().iter().rev() {
+            for i in (0..64).rev() {
+                acc *= acc;
+                acc.conditional_assign(&(acc * self), Choice::from(((word >> i) & 1) as u8));
+            }
+        }
 
-/// Generic scalar type with primitive functionality.
-///
-/// This type provides a baseline level of scalar arithmetic functionality
-/// which is always available for all curves, regardless of if they implement
-/// any arithmetic traits.
-///
-/// # `serde` support
-///
-/// When the optional `serde` feature of this create is enabled, [`Serialize`]
-/// and [`Deserialize`] impls are provided for this type.
-///
-/// The serialization is a fixed-width big endian encoding. When used with
-/// textual formats, the binary data is encoded as hexadecimal.
-// TODO(tarcieri): use `crypto-bigint`'s `Residue` type, expose more functionality?
-#[derive(Copy, Clone, Debug, Default)]
-pub struct ScalarPrimitive<C: Curve> {
-    /// Inner unsigned integer type.
-    inner: C::Uint,
-}
+        acc
+    }
 
-impl<C> ScalarPrimitive<C>
-where
-    C: Curve,
-{
-    /// Zero scalar.
-    pub const ZERO: Self = Self {
-        inner: C::Uint::ZERO,
-    };
+    /// Raise this scalar to the power of `exp`, given as little endian
+    /// 64-bit words.
+    ///
+    /// ⚠️ WARNING: this operation is variable time with respect to the
+    /// exponent. Use [`ScalarPrimitive::pow`] for secret exponents.
+    pub fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut acc = Self::ONE;
+        let mut started = false;
 
-    /// Multiplicative identity.
-    pub const ONE: Self = Self {
-        inner: C::Uint::ONE,
-    };
+        for word in exp.as_ref().iter().rev() {
+            for i in (0..64).rev() {
+                if started {
+                    acc *= acc;
+                }
 
-    /// Scalar modulus.
-    pub const MODULUS: C::Uint = C::ORDER;
+                if (word >> i) & 1 == 1 {
+                    acc *= self;
+                    started = true;
+                }
+            }
+        }
 
-    /// Generate a random [`ScalarPrimitive`].
-    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
-        Self {
-            inner: C::Uint::random_mod(rng, &NonZero::new(Self::MODULUS).unwrap()),
+        acc
+    }
+
+    /// Fill `out` with the successive powers `[1, x, x^2, ...]` of this
+    /// scalar `x`.
+    pub fn powers_into(&self, out: &mut [Self]) {
+        let mut acc = Self::ONE;
+
+        for power in out {
+            *power = acc;
+            acc *= self;
         }
     }
 
-    /// Create a new scalar from [`Curve::Uint`].
-    pub fn new(uint: C::Uint) -> CtOption<Self> {
-        CtOption::new(Self { inner: uint }, uint.ct_lt(&Self::MODULUS))
+    /// Compute the first `n` powers `[1, x, x^2, ..., x^(n-1)]` of this
+    /// scalar `x`.
+    ///
+    /// See [`ScalarPrimitive::powers_into`] for a variant which doesn't
+    /// allocate.
+    #[cfg(feature = "alloc")]
+    pub fn powers(&self, n: usize) -> Vec<Self> {
+        let mut ret = vec![Self::ZERO; n];
+        self.powers_into(&mut ret);
+        ret
     }
 
-    /// Decode [`ScalarPrimitive`] from big endian bytes.
-    pub fn from_be_bytes(bytes: FieldBytes<C>) -> CtOption<Self> {
-        Self::new(C::Uint::from_be_byte_array(bytes))
+    /// Compute the first `n` inverse powers `[1, x^-1, x^-2, ..., x^-(n-1)]`
+    /// of this scalar `x`.
+    ///
+    /// This requires only a single inversion. Returns none if this scalar is
+    /// zero.
+    #[cfg(feature = "alloc")]
+    pub fn inv_powers(&self, n: usize) -> CtOption<Vec<Self>> {
+        self.invert().map(|inv| inv.powers(n))
     }
 
-    /// Decode [`ScalarPrimitive`] from a big endian byte slice.
-    pub fn from_be_slice(slice: &[u8]) -> Result<Self> {
-        if slice.len() == C::Uint::BYTES {
-            Option::from(Self::from_be_bytes(GenericArray::clone_from_slice(slice))).ok_or(Error)
-        } else {
-            Err(Error)
+    /// Get the number of entries in a table of powers with the given
+    /// `window` size in bits, as created by `precompute_pow_table`, i.e.
+    /// `2^window`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not in the range `1..=8` accepted by
+    /// `precompute_pow_table`.
+    pub const fn pow_table_len(window: u32) -> usize {
+        assert!(window >= 1 && window <= 8, "window must be in 1..=8");
+        1 << window
+    }
+
+    /// Choose a window size for `precompute_pow_table` when the table will
+    /// be used for `num_exponentiations` exponentiations to full-width
+    /// exponents.
+    ///
+    /// This minimizes the estimated number of multiplications, i.e. the
+    /// `2^window` needed to build the table plus `BITS / window` per
+    /// exponentiation. Squarings are the same for any window, and so aren't
+    /// counted. The result is in the range `1..=8` accepted by
+    /// `precompute_pow_table`.
+    pub fn recommended_window(num_exponentiations: usize) -> u32 {
+        let cost = |window: u32| {
+            let per_exp = (Self::BITS + window as usize - 1) / window as usize;
+            num_exponentiations as u128 * per_exp as u128 + Self::pow_table_len(window) as u128
+        };
+
+        (1..=8).min_by_key(|&window| cost(window)).unwrap_or(1)
+    }
+
+    /// Raise this scalar to the power of `exp`, considering only the low
+    /// `bits` bits of the exponent, in constant time.
+    ///
+    /// The running time depends only on `bits`, making this cheaper than
+    /// [`ScalarPrimitive::pow`] for short secret exponents.
+    ///
+    /// ⚠️ WARNING: the exponent MUST fit in `bits` bits. Any higher bits are
+    /// silently ignored in release builds (checked by a debug assertion),
+    /// and `bits` itself is not treated as secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` exceeds [`ScalarPrimitive::BITS`].
+    pub fn pow_bounded(&self, exp: &Self, bits: u32) -> Self {
+        let bits = bits as usize;
+        assert!(bits <= Self::BITS, "bit length exceeds scalar size");
+        debug_assert!(
+            bits == Self::BITS || bool::from((exp.inner >> bits).is_zero()),
+            "exponent does not fit in the given bit length"
+        );
+        self.pow_bits(&exp.inner, bits)
+    }
+
+    /// Multiply `base` by this scalar in an arbitrary additive group, given
+    /// its `identity` element and `add` and `double` operations.
+    ///
+    /// This performs double-and-add over all [`ScalarPrimitive::BITS`] bits
+    /// of the scalar, always computing the addition and selecting its result
+    /// with [`ConditionallySelectable`], so that the sequence of group
+    /// operations doesn't depend on the value of the scalar. The group
+    /// operations themselves must be constant-time for the result to be.
+    pub fn mul_with<T>(
+        &self,
+        identity: T,
+        add: impl Fn(&T, &T) -> T,
+        double: impl Fn(&T) -> T,
+        base: &T,
+    ) -> T
+    where
+        T: ConditionallySelectable,
+    {
+        let mut acc = identity;
+
+        for i in (0..Self::BITS).rev() {
+            acc = double(&acc);
+            acc.conditional_assign(&add(&acc, base), self.bit(i));
         }
+
+        acc
     }
 
-    /// Decode [`ScalarPrimitive`] from little endian bytes.
-    pub fn from_le_bytes(bytes: FieldBytes<C>) -> CtOption<Self> {
-        Self::new(C::Uint::from_le_byte_array(bytes))
+    /// Compute the multiplicative inverse of this scalar using Fermat's
+    /// little theorem, i.e. by computing `self^(n - 2)`.
+    ///
+    /// This is fully constant-time but considerably slower than
+    /// [`ScalarPrimitive::invert`]. It requires the curve order `n` to be
+    /// prime: for composite orders the result is meaningless.
+    ///
+    /// Returns none if the scalar is zero.
+    pub fn invert_fermat(&self) -> CtOption<Self> {
+        let exp = Self::MODULUS.checked_sub(&C::Uint::from(2u64)).unwrap();
+        CtOption::new(self.pow_bits(&exp, Self::BITS), !self.is_zero())
     }
 
-    /// Decode [`ScalarPrimitive`] from a little endian byte slice.
-    pub fn from_le_slice(slice: &[u8]) -> Result<Self> {
-        if slice.len() == C::Uint::BYTES {
-            Option::from(Self::from_le_bytes(GenericArray::clone_from_slice(slice))).ok_or(Error)
-        } else {
-            Err(Error)
+    /// Compute `sqrt(u / v)` as described in [RFC 9380 Section 5.2][1], using
+    /// the constant-time algorithm of [RFC 9380 Appendix F.2.1.1][2].
+    ///
+    /// Returns `(true, sqrt(u / v))` if `u / v` is square, and otherwise
+    /// `(false, sqrt(z * u / v))`. `z` MUST be a non-square modulo the
+    /// (prime) order `n`, and `v` MUST be non-zero: otherwise the result is
+    /// unspecified. Like the reference algorithm, this returns `(false, 0)`
+    /// for `u = 0`.
+    ///
+    /// The computation is constant-time with respect to `u`, `v` and `z`.
+    ///
+    /// [1]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2
+    /// [2]: https://www.rfc-editor.org/rfc/rfc9380.html#appendix-F.2.1.1
+    pub fn sqrt_ratio_with_z(u: &Self, v: &Self, z: &Self) -> (Choice, Self) {
+        let square = |mut x: Self, times: usize| {
+            for _ in 0..times {
+                x *= x;
+            }
+            x
+        };
+
+        // `n - 1 = 2^c1 * c2` with `c2` odd
+        let n_minus_one = Self::MODULUS.checked_sub(&C::Uint::ONE).unwrap();
+        let c1 = (0..Self::BITS)
+            .find(|&i| bool::from(uint_bit(&n_minus_one, i)))
+            .expect("order is not an odd prime");
+        let c2 = n_minus_one >> c1;
+        let c3 = c2 >> 1;
+        let c4 = (C::Uint::ONE << c1).checked_sub(&C::Uint::ONE).unwrap();
+        let c6 = z.pow_bits(&c2, Self::BITS);
+        let c7 = z.pow_bits(&c3.checked_add(&C::Uint::ONE).unwrap(), Self::BITS);
+
+        let mut tv1 = c6;
+        let mut tv2 = v.pow_bits(&c4, c1);
+        let mut tv3 = tv2 * tv2 * v;
+        let mut tv5 = (*u * tv3).pow_bits(&c3, Self::BITS) * tv2;
+        tv2 = tv5 * v;
+        tv3 = tv5 * u;
+        let mut tv4 = tv3 * tv2;
+        tv5 = square(tv4, c1 - 1);
+        let is_square = tv5.ct_eq(&Self::ONE);
+        tv2 = tv3 * c7;
+        tv5 = tv4 * tv1;
+        tv3 = Self::conditional_select(&tv2, &tv3, is_square);
+        tv4 = Self::conditional_select(&tv5, &tv4, is_square);
+
+        for i in (2..=c1).rev() {
+            tv5 = square(tv4, i - 2);
+            let e1 = tv5.ct_eq(&Self::ONE);
+            tv2 = tv3 * tv1;
+            tv1 *= tv1;
+            tv5 = tv4 * tv1;
+            tv3 = Self::conditional_select(&tv2, &tv3, e1);
+            tv4 = Self::conditional_select(&tv5, &tv4, e1);
         }
+
+        (is_square, tv3)
     }
 
-    /// Borrow the inner `C::Uint`.
-    pub fn as_uint(&self) -> &C::Uint {
-        &self.inner
+    /// Compute the Lagrange coefficient at zero for the share with the given
+    /// `index`, i.e. `∏ x_j / (x_j - index)` over all `x_j` in `other_indices`.
+    ///
+    /// This is the coefficient used to reconstruct a Shamir-shared secret
+    /// from the share at `index`.
+    ///
+    /// `other_indices` must not contain `index` itself. Returns none if any
+    /// of `other_indices` is equal to `index`.
+    pub fn lagrange_coefficient_at_zero(index: &Self, other_indices: &[Self]) -> CtOption<Self> {
+        let mut numerator = Self::ONE;
+        let mut denominator = Self::ONE;
+
+        for x_j in other_indices {
+            numerator *= x_j;
+            denominator *= *x_j - index;
+        }
+
+        denominator.invert().map(|inv| numerator * inv)
     }
 
-    /// Borrow the inner limbs as a slice.
-    pub fn as_limbs(&self) -> &[Limb] {
-        self.inner.as_ref()
+    /// Reconstruct a secret which was split into additive `shares`, i.e.
+    /// compute their sum modulo the scalar modulus.
+    ///
+    /// This is the inverse of [`ScalarPrimitive::split_additive`].
+    pub fn sum_shares(shares: &[Self]) -> Self {
+        shares.iter().fold(Self::ZERO, |acc, share| acc + share)
     }
 
-    /// Is this [`ScalarPrimitive`] value equal to zero?
-    pub fn is_zero(&self) -> Choice {
-        self.inner.is_zero()
+    /// Split this scalar into `n` random additive shares which sum to it
+    /// modulo the scalar modulus.
+    ///
+    /// The first `n - 1` shares are sampled uniformly at random and the last
+    /// is computed as `self - sum(others)`, so any `n - 1` shares reveal
+    /// nothing about the secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[cfg(feature = "alloc")]
+    pub fn split_additive(&self, rng: &mut impl CryptoRngCore, n: usize) -> Vec<Self> {
+        assert!(n > 0, "number of shares must be non-zero");
+
+        let mut shares = (1..n).map(|_| Self::random(rng)).collect::<Vec<_>>();
+        shares.push(*self - Self::sum_shares(&shares));
+        shares
     }
 
-    /// Is this [`ScalarPrimitive`] value even?
-    pub fn is_even(&self) -> Choice {
-        self.inner.is_even()
+    /// Hash the given messages to a scalar using the `hash_to_field` routine
+    /// described in [RFC 9380 Section 5][1].
+    ///
+    /// Expands the messages to `L = ceil((ceil(log2(n)) + k) / 8)` bytes,
+    /// where `k` is the target security level of `ceil(log2(n)) / 2` bits,
+    /// then interprets them as a big endian integer and reduces it modulo
+    /// the scalar field order `n` in constant time.
+    ///
+    /// This takes a single domain separation tag `dst`, rather than one per
+    /// message, since [`ExpandMsg::expand_message`] expands all messages
+    /// under one tag.
+    ///
+    /// # Errors
+    /// See implementors of [`ExpandMsg`] for errors:
+    /// - [`ExpandMsgXmd`]
+    /// - [`ExpandMsgXof`]
+    ///
+    /// [1]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5
+    /// [`ExpandMsgXmd`]: crate::hash2curve::ExpandMsgXmd
+    /// [`ExpandMsgXof`]: crate::hash2curve::ExpandMsgXof
+    #[cfg(feature = "hash2curve")]
+    pub fn hash_to_scalar<'a, X>(msgs: &[&[u8]], dst: &'a [u8]) -> Result<Self>
+    where
+        X: ExpandMsg<'a>,
+        FieldSize<C>: Mul<U2>,
+        Prod<FieldSize<C>, U2>: ArrayLength<u8>,
+    {
+        let modulus_bits = Self::modulus_bits();
+        let len_in_bytes = (modulus_bits + (modulus_bits + 1) / 2 + 7) / 8;
+        let mut expander = X::expand_message(msgs, dst, len_in_bytes)?;
+
+        // `L` is at most `1.5 * BYTES + 1`, so it fits in a double-width buffer
+        let mut okm = GenericArray::<u8, Prod<FieldSize<C>, U2>>::default();
+        let okm = &mut okm[..len_in_bytes];
+        expander.fill_bytes(okm);
+        Ok(Self::reduce_be_slice_ct(okm))
     }
 
-    /// Is this [`ScalarPrimitive`] value odd?
-    pub fn is_odd(&self) -> Choice {
-        self.inner.is_odd()
+    /// Deterministically derive a scalar from a `seed` and a `counter`, e.g.
+    /// for child key derivation, by hashing them with the digest `D`.
+    ///
+    /// The hash input is `seed || counter`, with `counter` encoded as 8 big
+    /// endian bytes. Since the counter has a fixed width, distinct inputs
+    /// never collide. The digest output is interpreted as a big endian
+    /// integer and reduced modulo the order in constant time.
+    ///
+    /// No domain separation is applied: callers MUST include an
+    /// application-specific tag in `seed` so that scalars derived for
+    /// different purposes are independent.
+    ///
+    /// ⚠️ WARNING: unless the digest output is substantially (e.g. 64 bits)
+    /// longer than the order, the result is slightly biased. Use
+    /// [`ScalarPrimitive::hash_to_scalar`] where uniformity matters.
+    #[cfg(feature = "digest")]
+    pub fn derive_with<D: Digest>(seed: &[u8], counter: u64) -> Self {
+        let digest = D::new()
+            .chain_update(seed)
+            .chain_update(counter.to_be_bytes())
+            .finalize();
+        Self::reduce_be_slice_ct(&digest)
     }
 
-    /// Encode [`ScalarPrimitive`] as big endian bytes.
-    pub fn to_be_bytes(&self) -> FieldBytes<C> {
-        self.inner.to_be_byte_array()
+    /// Deterministically derive a scalar from a `seed` and a `counter` using
+    /// SHA-256.
+    ///
+    /// See [`ScalarPrimitive::derive_with`] for details.
+    #[cfg(feature = "sha2")]
+    pub fn derive(seed: &[u8], counter: u64) -> Self {
+        Self::derive_with::<sha2::Sha256>(seed, counter)
     }
 
-    /// Encode [`ScalarPrimitive`] as little endian bytes.
-    pub fn to_le_bytes(&self) -> FieldBytes<C> {
-        self.inner.to_le_byte_array()
+    /// Decode [`ScalarPrimitive`] from 64-bit words, least significant first.
+    fn from_u64_digits(words: impl Iterator<Item = u64>) -> CtOption<Self> {
+        let mut inner = C::Uint::ZERO;
+        let mut overflow = Choice::from(0);
+
+        for (i, word) in words.enumerate() {
+            let offset = i * 64;
+
+            if offset >= Self::BITS {
+                overflow |= !word.ct_eq(&0);
+                continue;
+            }
+
+            if offset + 64 > Self::BITS {
+                overflow |= !(word >> (Self::BITS - offset)).ct_eq(&0);
+            }
+
+            inner = inner | (C::Uint::from(word) << offset);
+        }
+
+        Self::new(inner).and_then(|ret| CtOption::new(ret, !overflow))
     }
 
-    /// Convert to a `C::Uint`.
-    pub fn to_uint(&self) -> C::Uint {
-        self.inner
+    /// Is `scalar` non-zero and distinct from all of `prev`?
+    fn is_distinct_nonzero(prev: &[Self], scalar: &Self) -> bool {
+        !bool::from(scalar.is_zero()) && !prev.contains(scalar)
+    }
+
+    /// Compute `self * 256 + byte` modulo the scalar modulus.
+    fn shl8_add(self, byte: u8) -> Self {
+        let mut ret = self;
+
+        for _ in 0..8 {
+            ret += ret;
+        }
+
+        ret + Self {
+            inner: C::Uint::from(u64::from(byte)),
+        }
+    }
+
+    /// Number of bits in the scalar modulus, i.e. `ceil(log2(n))`.
+    #[cfg(feature = "hash2curve")]
+    fn modulus_bits() -> usize {
+        uint_bits(&Self::MODULUS)
+    }
+
+    /// Get the bit at position `i` of the inner integer, counting from the
+    /// least significant bit.
+    pub(super) fn bit(&self, i: usize) -> Choice {
+        uint_bit(&self.inner, i)
+    }
+
+    /// Raise this scalar to the power of the lowest `bits` bits of `exp`
+    /// using constant-time square-and-multiply.
+    fn pow_bits(&self, exp: &C::Uint, bits: usize) -> Self {
+        let mut acc = Self::ONE;
+
+        for i in (0..bits).rev() {
+            acc *= acc;
+            acc.conditional_assign(&(acc * self), uint_bit(exp, i));
+        }
+
+        acc
+    }
+}
+
+impl<C> ScalarPrimitive<C>
+where
+    C: ScalarSqrtParameters,
+{
+    /// Compute the square root of this scalar, if it exists, in constant
+    /// time.
+    ///
+    /// Uses [`ScalarPrimitive::sqrt_ratio`] with `v = 1`, so which of the two
+    /// roots is returned is determined by that algorithm.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        let (is_square, root) = Self::sqrt_ratio(self, &Self::ONE);
+        CtOption::new(root, is_square | self.is_zero())
+    }
+
+    /// Compute `sqrt(u / v)` as described in [RFC 9380 Section 5.2][1],
+    /// using the curve's non-square [`ScalarSqrtParameters::Z`].
+    ///
+    /// See [`ScalarPrimitive::sqrt_ratio_with_z`] for details.
+    ///
+    /// [1]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2
+    pub fn sqrt_ratio(u: &Self, v: &Self) -> (Choice, Self) {
+        Self::sqrt_ratio_with_z(u, v, &C::Z)
     }
 }
 
@@ -157,9 +2340,117 @@ where
     C: CurveArithmetic,
 {
     /// Convert [`ScalarPrimitive`] into a given curve's scalar type.
-    pub(super) fn to_scalar(self) -> Scalar<C> {
+    pub fn to_scalar(self) -> Scalar<C> {
         Scalar::<C>::from_uint_unchecked(self.inner)
     }
+
+    /// Convert a given curve's scalar type into a [`ScalarPrimitive`].
+    ///
+    /// This is the inverse of [`ScalarPrimitive::to_scalar`]. Note that
+    /// [`CurveArithmetic::Scalar`] is also required to impl
+    /// `From<ScalarPrimitive<C>>` and `Into<ScalarPrimitive<C>>`.
+    pub fn from_scalar(scalar: &Scalar<C>) -> Self {
+        let ret = Self {
+            inner: (*scalar).into(),
+        };
+        ret.debug_assert_canonical();
+        ret
+    }
+
+    /// Derive a [`NonZeroScalar`] from big endian bytes of any length, e.g.
+    /// seed entropy for a private key, by reducing them modulo the scalar
+    /// modulus in constant time.
+    ///
+    /// Returns none if the result is zero, which is astronomically unlikely
+    /// for random input but can be forced by adversarial input such as a
+    /// multiple of the order. No fallback value is substituted.
+    pub fn nonzero_from_bytes_mod_order(bytes: &[u8]) -> CtOption<NonZeroScalar<C>> {
+        NonZeroScalar::new(Self::reduce_be_slice_ct(bytes).to_scalar())
+    }
+}
+
+/// Guard providing mutable access to the inner integer of a
+/// [`ScalarPrimitive`], created with [`ScalarPrimitive::edit`].
+///
+/// The integer is reduced modulo the scalar modulus when the guard is
+/// dropped.
+#[derive(Debug)]
+pub struct ScalarEditGuard<'a, C: Curve> {
+    /// Scalar being edited.
+    scalar: &'a mut ScalarPrimitive<C>,
+}
+
+impl<'a, C> Deref for ScalarEditGuard<'a, C>
+where
+    C: Curve,
+{
+    type Target = C::Uint;
+
+    fn deref(&self) -> &C::Uint {
+        &self.scalar.inner
+    }
+}
+
+impl<'a, C> DerefMut for ScalarEditGuard<'a, C>
+where
+    C: Curve,
+{
+    fn deref_mut(&mut self) -> &mut C::Uint {
+        &mut self.scalar.inner
+    }
+}
+
+impl<'a, C> Drop for ScalarEditGuard<'a, C>
+where
+    C: Curve,
+{
+    fn drop(&mut self) {
+        *self.scalar = ScalarPrimitive::new_reduced_flagged(self.scalar.inner).0;
+    }
+}
+
+/// Get the bit at position `i` of `x`, counting from the least significant bit.
+fn uint_bit<T: Integer>(x: &T, i: usize) -> Choice {
+    let limb = x.as_ref()[i / Limb::BITS];
+    Choice::from(((limb.0 >> (i % Limb::BITS)) & 1) as u8)
+}
+
+/// Get the number of bits needed to represent `x`, i.e. the position of its
+/// most significant set bit plus one.
+fn uint_bits<T: Integer>(x: &T) -> usize {
+    let limbs = x.as_ref();
+
+    limbs
+        .iter()
+        .rposition(|limb| limb.0 != 0)
+        .map(|i| (i + 1) * Limb::BITS - limbs[i].0.leading_zeros() as usize)
+        .unwrap_or(0)
+}
+
+/// Compute `gcd(a, b)` for an odd `b` in constant time with respect to `a`,
+/// using a fixed number of iterations of the binary GCD algorithm.
+fn uint_gcd_odd<T: Integer>(mut a: T, mut b: T) -> T {
+    debug_assert!(bool::from(b.is_odd()));
+
+    // Invariant: `b` is odd, so halving `a` preserves `gcd(a, b)`
+    for _ in 0..(2 * T::BITS) {
+        let a_is_odd = a.is_odd();
+        let swap = a_is_odd & a.ct_lt(&b);
+        T::conditional_swap(&mut a, &mut b, swap);
+        a.conditional_assign(&a.checked_sub(&b).unwrap_or(a), a_is_odd);
+        a = a >> 1;
+    }
+
+    b
+}
+
+/// Compute `x mod m`.
+// NOTE: the `Rem<NonZero<Uint>>` impl in `crypto-bigint` v0.5.0-pre.1 recurses
+// infinitely, so the remainder is computed from the quotient instead.
+fn uint_rem<T: Integer>(x: &T, m: &NonZero<T>) -> T {
+    let quotient = *x / *m;
+    let product = quotient.checked_mul(m).unwrap();
+    x.checked_sub(&product).unwrap()
 }
 
 // TODO(tarcieri): better encapsulate this?
@@ -181,6 +2472,10 @@ where
             inner: C::Uint::conditional_select(&a.inner, &b.inner, choice),
         }
     }
+
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        C::Uint::conditional_swap(&mut a.inner, &mut b.inner, choice);
+    }
 }
 
 impl<C> ConstantTimeEq for ScalarPrimitive<C>
@@ -336,6 +2631,101 @@ where
     }
 }
 
+impl<C> Mul<ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.mul(&other)
+    }
+}
+
+impl<C> Mul<&ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self {
+        // Schoolbook multiplication followed by a single constant-time
+        // reduction of the double-width product.
+        let (lo, hi) = wide::mul_wide(&self.inner, &other.inner);
+        Self {
+            inner: WideReducer::<C>::new().reduce_wide(&lo, &hi),
+        }
+    }
+}
+
+impl<C> MulAssign<ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<C> MulAssign<&ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn mul_assign(&mut self, other: &Self) {
+        *self = *self * other;
+    }
+}
+
+/// # Panics
+///
+/// Panics if the divisor is zero (or, for curves with a composite order, if
+/// it isn't coprime to the order). Use [`ScalarPrimitive::checked_div`] to
+/// handle this case instead.
+impl<C> Div<ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(&other).unwrap()
+    }
+}
+
+/// # Panics
+///
+/// Panics if the divisor is zero (or, for curves with a composite order, if
+/// it isn't coprime to the order). Use [`ScalarPrimitive::checked_div`] to
+/// handle this case instead.
+impl<C> Div<&ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn div(self, other: &Self) -> Self {
+        self.checked_div(other).unwrap()
+    }
+}
+
+impl<C> DivAssign<ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<C> DivAssign<&ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn div_assign(&mut self, other: &Self) {
+        *self = *self / other;
+    }
+}
+
 impl<C> Neg for ScalarPrimitive<C>
 where
     C: Curve,
@@ -447,3 +2837,26 @@ where
             .ok_or_else(|| de::Error::custom("scalar out of range"))
     }
 }
+
+#[cfg(feature = "borsh")]
+impl<C> BorshSerialize for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<C> BorshDeserialize for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = FieldBytes::<C>::default();
+        reader.read_exact(&mut bytes)?;
+        Option::from(Self::from_le_bytes(bytes))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "scalar out of range"))
+    }
+}
@@ -0,0 +1,140 @@
+//! Minimal big endian encoding of [`ScalarPrimitive`] for use with `serde`.
+//!
+//! Some protocols (e.g. DER `INTEGER`, certain Bitcoin script contexts) encode
+//! integers using the shortest possible big endian byte string, i.e. with all
+//! leading zero bytes stripped. This module provides that encoding along with
+//! [`serialize`] and [`deserialize`] functions suitable for use with
+//! `#[serde(with = "elliptic_curve::scalar::serde_minimal")]`.
+//!
+//! Binary serializers emit the encoding as a length-prefixed byte string,
+//! whereas textual formats encode it as hexadecimal.
+//!
+//! The [`signed`] submodule provides the same functionality for signed
+//! contexts such as ASN.1 `INTEGER`s, where a zero byte is prepended whenever
+//! the high bit of the first byte would otherwise be set.
+//!
+//! ⚠️ WARNING: the length of the encoding depends on the value of the scalar,
+//! so it is not constant-time and should not be used with secret scalars when
+//! the length may be observed.
+
+use super::ScalarPrimitive;
+use crate::{bigint::Integer, Curve, Error, FieldBytes, Result};
+use alloc::vec::Vec;
+use serdect::serde::{de, Deserializer, Serializer};
+
+/// Encode a [`ScalarPrimitive`] as a minimal big endian byte string.
+///
+/// When `signed` is true, a leading zero byte is added if the high bit of the
+/// first byte is set, so the encoding is interpreted as a non-negative signed
+/// integer. Zero is encoded as an empty byte string when unsigned, and as a
+/// single zero byte when signed.
+pub fn to_bytes<C>(scalar: &ScalarPrimitive<C>, signed: bool) -> Vec<u8>
+where
+    C: Curve,
+{
+    let bytes = scalar.to_be_bytes();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    let minimal = &bytes[start..];
+
+    let mut out = Vec::with_capacity(minimal.len() + 1);
+
+    if signed && minimal.first().map_or(true, |&b| b & 0x80 != 0) {
+        out.push(0);
+    }
+
+    out.extend_from_slice(minimal);
+    out
+}
+
+/// Decode a [`ScalarPrimitive`] from a minimal big endian byte string.
+///
+/// When `signed` is true, the input is interpreted as a signed integer and
+/// negative values are rejected.
+///
+/// When `strict` is true, non-minimal encodings (i.e. ones with superfluous
+/// leading zero bytes) are rejected. Otherwise any number of leading zero
+/// bytes is accepted.
+///
+/// Values which overflow the scalar modulus are always rejected.
+pub fn from_bytes<C>(bytes: &[u8], signed: bool, strict: bool) -> Result<ScalarPrimitive<C>>
+where
+    C: Curve,
+{
+    if signed {
+        if bytes.first().map_or(true, |&b| b & 0x80 != 0) {
+            return Err(Error);
+        }
+
+        if strict && bytes.len() > 1 && bytes[0] == 0 && bytes[1] & 0x80 == 0 {
+            return Err(Error);
+        }
+    } else if strict && bytes.first() == Some(&0) {
+        return Err(Error);
+    }
+
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    let bytes = &bytes[start..];
+
+    if bytes.len() > C::Uint::BYTES {
+        return Err(Error);
+    }
+
+    let mut repr = FieldBytes::<C>::default();
+    repr[(C::Uint::BYTES - bytes.len())..].copy_from_slice(bytes);
+    Option::from(ScalarPrimitive::from_be_bytes(repr)).ok_or(Error)
+}
+
+/// Serialize a [`ScalarPrimitive`] using the unsigned minimal encoding.
+pub fn serialize<C, S>(
+    scalar: &ScalarPrimitive<C>,
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error>
+where
+    C: Curve,
+    S: Serializer,
+{
+    serdect::slice::serialize_hex_upper_or_bin(&to_bytes(scalar, false), serializer)
+}
+
+/// Deserialize a [`ScalarPrimitive`] from the unsigned minimal encoding,
+/// rejecting non-minimal encodings.
+pub fn deserialize<'de, C, D>(deserializer: D) -> core::result::Result<ScalarPrimitive<C>, D::Error>
+where
+    C: Curve,
+    D: Deserializer<'de>,
+{
+    let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+    from_bytes(&bytes, false, true)
+        .map_err(|_| de::Error::custom("invalid minimal scalar encoding"))
+}
+
+/// Minimal encoding of [`ScalarPrimitive`] for signed contexts.
+///
+/// Intended for use with
+/// `#[serde(with = "elliptic_curve::scalar::serde_minimal::signed")]`.
+pub mod signed {
+    use super::{from_bytes, to_bytes, ScalarPrimitive};
+    use crate::Curve;
+    use serdect::serde::{de, Deserializer, Serializer};
+
+    /// Serialize a [`ScalarPrimitive`] using the signed minimal encoding.
+    pub fn serialize<C, S>(scalar: &ScalarPrimitive<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Curve,
+        S: Serializer,
+    {
+        serdect::slice::serialize_hex_upper_or_bin(&to_bytes(scalar, true), serializer)
+    }
+
+    /// Deserialize a [`ScalarPrimitive`] from the signed minimal encoding,
+    /// rejecting negative and non-minimal encodings.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<ScalarPrimitive<C>, D::Error>
+    where
+        C: Curve,
+        D: Deserializer<'de>,
+    {
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+        from_bytes(&bytes, true, true)
+            .map_err(|_| de::Error::custom("invalid minimal scalar encoding"))
+    }
+}
@@ -0,0 +1,256 @@
+//! Limb-level double-width multiplication and reduction modulo the order of a
+//! [`ScalarPrimitive`][`super::ScalarPrimitive`].
+//!
+//! Unlike [`BarrettContext`][`super::BarrettContext`], this doesn't need a
+//! double-width integer type, so it works for any [`Curve::Uint`]. Products
+//! are kept as a pair of `C::Uint` halves and reduced one limb at a time by
+//! schoolbook long division (Algorithm D in Knuth's TAOCP vol. 2, §4.3.1),
+//! estimating each quotient limb in constant time with the reciprocal from
+//! "Improved Division by Invariant Integers" by Niels Möller and Torbjörn
+//! Granlund (<https://gmplib.org/~tege/division-paper.pdf>).
+
+use crate::{
+    bigint::{CheckedSub, Encoding, Integer, Limb, WideWord, Word, Zero},
+    Curve,
+};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+/// Parameters for reducing multi-limb integers modulo the scalar order `n`.
+///
+/// These only depend on the order and are cheap to compute: the only
+/// division is a (variable-time) division of a double limb by the most
+/// significant limb of the order.
+pub(super) struct WideReducer<C: Curve> {
+    /// Order `n` shifted left by `shift` bits so its most significant bit is
+    /// set.
+    modulus: C::Uint,
+
+    /// Number of leading zero bits of `n`.
+    shift: usize,
+
+    /// Reciprocal `floor((2^(2 * W) - 1) / d) - 2^W` of the most significant
+    /// limb `d` of `modulus`, where `W` is `Limb::BITS`.
+    reciprocal: Word,
+}
+
+impl<C: Curve> WideReducer<C> {
+    /// Precompute the reduction parameters for the order of `C`.
+    pub(super) fn new() -> Self {
+        let order = C::ORDER;
+        let order = order.as_ref();
+        let top = order
+            .iter()
+            .rposition(|limb| limb.0 != 0)
+            .expect("order is nonzero");
+        let shift = (order.len() - 1 - top) * Limb::BITS + order[top].0.leading_zeros() as usize;
+        let modulus = C::ORDER << shift;
+
+        let d = WideWord::from(modulus.as_ref()[order.len() - 1].0);
+        let reciprocal = (WideWord::MAX / d - (1 << Limb::BITS)) as Word;
+
+        Self {
+            modulus,
+            shift,
+            reciprocal,
+        }
+    }
+
+    /// Reduce `hi * 2^BITS + lo` modulo the order, where `hi` MUST be less
+    /// than the order, as is the case for the product of two scalars.
+    pub(super) fn reduce_wide(&self, lo: &C::Uint, hi: &C::Uint) -> C::Uint {
+        debug_assert!(bool::from(hi.ct_lt(&C::ORDER)));
+        let acc = lo
+            .as_ref()
+            .iter()
+            .rev()
+            .fold(*hi << self.shift, |acc, &limb| self.step(&acc, limb));
+        acc >> self.shift
+    }
+
+    /// Reduce the integer with the given limbs, from most to least
+    /// significant, modulo the order.
+    ///
+    /// This is constant-time with respect to the values of the limbs, but
+    /// not their number.
+    pub(super) fn reduce_limbs(&self, limbs: impl IntoIterator<Item = Limb>) -> C::Uint {
+        let acc = limbs
+            .into_iter()
+            .fold(C::Uint::ZERO, |acc, limb| self.step(&acc, limb));
+        acc >> self.shift
+    }
+
+    /// Compute `(acc * 2^W + limb * 2^shift) mod modulus`, where `acc` is a
+    /// multiple of `2^shift` less than `modulus`, so that the result is
+    /// `2^shift` times a residue modulo the order.
+    fn step(&self, acc: &C::Uint, limb: Limb) -> C::Uint {
+        let (acc, modulus) = (acc.as_ref(), self.modulus.as_ref());
+        let n = modulus.len();
+        let (limb_shift, bit_shift) = (self.shift / Limb::BITS, self.shift % Limb::BITS);
+
+        // Limb `i` of the `n + 1` limb dividend `x`. The low `shift` bits of
+        // `acc` are zero, so `limb * 2^shift` can be or'ed in.
+        let x = |i: usize| {
+            let mut ret = if i == 0 { 0 } else { acc[i - 1].0 };
+
+            if i == limb_shift {
+                ret |= limb.0 << bit_shift;
+            }
+
+            if bit_shift != 0 && i == limb_shift + 1 {
+                ret |= limb.0 >> (Limb::BITS - bit_shift);
+            }
+
+            Limb(ret)
+        };
+
+        // Estimate the quotient from the top limbs. Since `x < modulus * 2^W`
+        // the top limb of `x` is at most that of `modulus`, and if they are
+        // equal the quotient is clamped to `2^W - 1`.
+        let (x1, d) = (x(n), modulus[n - 1]);
+        let overflow = x1.ct_eq(&d);
+        let x1 = Limb::conditional_select(&x1, &Limb::ZERO, overflow);
+        let q = Limb::conditional_select(
+            &Limb(div2by1(x1.0, x(n - 1).0, d.0, self.reciprocal)),
+            &Limb::MAX,
+            overflow,
+        );
+
+        // The estimate exceeds the quotient by at most 2, so `x - q * modulus`
+        // needs at most two corrections.
+        let mut rem = C::Uint::ZERO.to_le_bytes();
+        let (mut top, mut carry, mut borrow) = (Limb::ZERO, Limb::ZERO, Limb::ZERO);
+
+        for i in 0..=n {
+            let (product, c) = Limb::ZERO.mac(q, *modulus.get(i).unwrap_or(&Limb::ZERO), carry);
+            let (limb, b) = x(i).sbb(product, borrow);
+            (carry, borrow) = (c, b);
+
+            if i < n {
+                set_limb(rem.as_mut(), i, limb);
+            } else {
+                top = limb;
+            }
+        }
+
+        let mut rem = C::Uint::from_le_bytes(rem);
+
+        for _ in 0..2 {
+            let negative = Choice::from((top.0 >> (Limb::BITS - 1)) as u8);
+            let addend = C::Uint::conditional_select(&C::Uint::ZERO, &self.modulus, negative);
+            let (sum, carry) = adc(&rem, &addend, Limb::ZERO);
+            rem = sum;
+            top = top.wrapping_add(carry);
+        }
+
+        debug_assert_eq!(top, Limb::ZERO);
+        rem
+    }
+}
+
+/// Compute the double-width product `a * b`, returned as `(lo, hi)` halves.
+pub(super) fn mul_wide<T: Integer + Encoding>(a: &T, b: &T) -> (T, T) {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    let n = a.len();
+    let mut lo = T::ZERO.to_le_bytes();
+    let mut hi = lo;
+
+    // Product scanning: column `k` is summed in the three limb accumulator
+    // `(c0, c1, c2)`, whose low limb is then limb `k` of the product.
+    let (mut c0, mut c1, mut c2) = (Limb::ZERO, Limb::ZERO, Limb::ZERO);
+
+    for k in 0..(2 * n) {
+        for i in k.saturating_sub(n - 1)..n.min(k + 1) {
+            let (sum, carry) = c0.mac(a[i], b[k - i], Limb::ZERO);
+            let (mid, carry) = c1.adc(carry, Limb::ZERO);
+            (c0, c1, c2) = (sum, mid, c2.wrapping_add(carry));
+        }
+
+        if k < n {
+            set_limb(lo.as_mut(), k, c0);
+        } else {
+            set_limb(hi.as_mut(), k - n, c0);
+        }
+
+        (c0, c1, c2) = (c1, c2, Limb::ZERO);
+    }
+
+    (T::from_le_bytes(lo), T::from_le_bytes(hi))
+}
+
+/// Compute `x * 2^(-W * rounds) mod n` for `x < n` by Montgomery reduction,
+/// where `W` is `Limb::BITS`.
+///
+/// The result is only meaningful if the order `n` is odd, which the caller
+/// must check.
+pub(super) fn montgomery_reduce<C: Curve>(x: &C::Uint, rounds: usize) -> C::Uint {
+    let order = C::ORDER;
+    let modulus = order.as_ref();
+    let n = modulus.len();
+
+    // `-n^(-1) mod 2^W` by Newton iteration. Any odd `n` is its own inverse
+    // modulo `2^3`, and each step doubles the number of correct bits.
+    let inv = (0..5).fold(modulus[0].0, |inv, _| {
+        inv.wrapping_mul((2 as Word).wrapping_sub(modulus[0].0.wrapping_mul(inv)))
+    });
+    let n_prime = inv.wrapping_neg();
+
+    (0..rounds).fold(*x, |acc, _| {
+        // `(acc + u * n) / 2^W`, where `u` is chosen so the division is
+        // exact, is at most `n`
+        let acc = acc.as_ref();
+        let u = Limb(acc[0].0.wrapping_mul(n_prime));
+        let mut ret = C::Uint::ZERO.to_le_bytes();
+        let mut carry = Limb::ZERO;
+
+        for i in 0..n {
+            let (limb, c) = acc[i].mac(u, modulus[i], carry);
+            carry = c;
+
+            if i > 0 {
+                set_limb(ret.as_mut(), i - 1, limb);
+            }
+        }
+
+        set_limb(ret.as_mut(), n - 1, carry);
+        let ret = C::Uint::from_le_bytes(ret);
+        ret.checked_sub(&order).unwrap_or(ret)
+    })
+}
+
+/// Compute `a + b + carry`, returning the sum along with the new carry.
+pub(super) fn adc<T: Integer + Encoding>(a: &T, b: &T, mut carry: Limb) -> (T, Limb) {
+    let mut sum = T::ZERO.to_le_bytes();
+
+    for (i, (a, b)) in a.as_ref().iter().zip(b.as_ref()).enumerate() {
+        let (limb, c) = a.adc(*b, carry);
+        set_limb(sum.as_mut(), i, limb);
+        carry = c;
+    }
+
+    (T::from_le_bytes(sum), carry)
+}
+
+/// Write `limb` as limb `i` of the little endian integer encoding `bytes`.
+fn set_limb(bytes: &mut [u8], i: usize, limb: Limb) {
+    bytes[(i * Limb::BYTES)..((i + 1) * Limb::BYTES)].copy_from_slice(&limb.0.to_le_bytes());
+}
+
+/// Compute `floor((u1 * 2^W + u0) / d)` in constant time, where `d` has its
+/// most significant bit set, `v` is its reciprocal and `u1 < d`.
+fn div2by1(u1: Word, u0: Word, d: Word, v: Word) -> Word {
+    debug_assert!(d >> (Limb::BITS - 1) == 1 && u1 < d);
+
+    let q = WideWord::from(v) * WideWord::from(u1)
+        + ((WideWord::from(u1) << Limb::BITS) | WideWord::from(u0));
+    let (q1, q0) = (((q >> Limb::BITS) as Word).wrapping_add(1), q as Word);
+    let r = u0.wrapping_sub(q1.wrapping_mul(d));
+
+    let r_gt_q0 = r.ct_gt(&q0);
+    let q1 = Word::conditional_select(&q1, &q1.wrapping_sub(1), r_gt_q0);
+    let r = Word::conditional_select(&r, &r.wrapping_add(d), r_gt_q0);
+
+    let r_ge_d = !d.ct_gt(&r);
+    Word::conditional_select(&q1, &q1.wrapping_add(1), r_ge_d)
+}
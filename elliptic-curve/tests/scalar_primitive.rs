@@ -0,0 +1,2836 @@
+//! `ScalarPrimitive` tests
+
+#![cfg(feature = "dev")]
+
+use core::num::NonZeroU32;
+use elliptic_curve::{
+    bigint::{NonZero, U128, U256, U512, U64},
+    dev::{MockCurve, Scalar, ScalarPrimitive},
+    generic_array::GenericArray,
+    scalar::{AddChain, AddChainStep, BitReader, BitWriter},
+    subtle::{Choice, ConditionallySelectable, CtOption},
+    Curve, Error, Field, ScalarParseError, ScalarSqrtParameters,
+};
+use hex_literal::hex;
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+/// Scalar modulus minus one, i.e. the largest valid scalar.
+const MODULUS_MINUS_ONE: [u8; 32] =
+    hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550");
+
+/// Curve with a small prime order, for testing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+struct SmallCurve;
+
+impl Curve for SmallCurve {
+    type Uint = U64;
+
+    const ORDER: U64 = U64::from_u64(1_000_003);
+}
+
+/// Curve whose order is the P-256 base field modulus, e.g. allowing the
+/// `hash_to_field` test vectors from RFC 9380 to be checked.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+struct P256BaseField;
+
+impl Curve for P256BaseField {
+    type Uint = U256;
+
+    const ORDER: U256 =
+        U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+}
+
+/// Curve with a small composite order `8 * 1_000_003`, for testing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+struct CompositeCurve;
+
+impl Curve for CompositeCurve {
+    type Uint = U64;
+
+    const ORDER: U64 = U64::from_u64(8 * 1_000_003);
+}
+
+#[cfg(feature = "serde")]
+mod serde_minimal {
+    use super::{ScalarPrimitive, MODULUS_MINUS_ONE};
+    use elliptic_curve::{
+        dev::MockCurve,
+        scalar::serde_minimal::{from_bytes, to_bytes},
+    };
+
+    #[test]
+    fn round_trip_unsigned() {
+        let scalar = ScalarPrimitive::from(0x1234u64);
+        let bytes = to_bytes(&scalar, false);
+        assert_eq!(bytes, [0x12, 0x34]);
+        assert_eq!(from_bytes(&bytes, false, true), Ok(scalar));
+    }
+
+    #[test]
+    fn round_trip_signed() {
+        let scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+        let bytes = to_bytes(&scalar, true);
+        assert_eq!(bytes.len(), 33);
+        assert_eq!(bytes[0], 0);
+        assert_eq!(from_bytes(&bytes, true, true), Ok(scalar));
+    }
+
+    #[test]
+    fn zero() {
+        assert!(to_bytes(&ScalarPrimitive::ZERO, false).is_empty());
+        assert_eq!(to_bytes(&ScalarPrimitive::ZERO, true), [0]);
+        assert_eq!(from_bytes(&[], false, true), Ok(ScalarPrimitive::ZERO));
+        assert_eq!(from_bytes(&[0], true, true), Ok(ScalarPrimitive::ZERO));
+    }
+
+    #[test]
+    fn reject_non_minimal() {
+        let bytes = [0x00, 0x12, 0x34];
+        assert!(from_bytes::<MockCurve>(&bytes, false, true).is_err());
+        assert!(from_bytes::<MockCurve>(&bytes, true, true).is_err());
+        assert_eq!(
+            from_bytes(&bytes, false, false),
+            Ok(ScalarPrimitive::from(0x1234u64))
+        );
+    }
+
+    #[test]
+    fn reject_negative() {
+        assert!(from_bytes::<MockCurve>(&[0x80], true, false).is_err());
+        assert!(from_bytes::<MockCurve>(&[0x80], false, false).is_ok());
+    }
+
+    #[test]
+    fn skip_if_zero() {
+        use elliptic_curve::scalar::is_zero;
+
+        assert!(is_zero(&ScalarPrimitive::default()));
+        assert!(!is_zero(&ScalarPrimitive::ONE));
+    }
+
+    #[test]
+    fn reject_overflow() {
+        let mut bytes = MODULUS_MINUS_ONE;
+        bytes[31] += 1;
+        assert!(from_bytes::<MockCurve>(&bytes, false, true).is_err());
+        assert!(from_bytes::<MockCurve>(&[1; 33], false, false).is_err());
+    }
+}
+
+#[test]
+fn mul() {
+    let a = ScalarPrimitive::from(0xffff_ffffu64);
+    let b = ScalarPrimitive::from(0x1_0000_0001u64);
+    assert_eq!(a * b, ScalarPrimitive::from(0xffff_ffff_ffff_ffffu64));
+
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert_eq!(minus_one * minus_one, ScalarPrimitive::ONE);
+    assert_eq!(minus_one * ScalarPrimitive::ZERO, ScalarPrimitive::ZERO);
+}
+
+#[test]
+fn mul_matches_reduce_wide_uint() {
+    use elliptic_curve::bigint::{Concat, Integer, Split, Zero};
+
+    /// Order which leaves more than a limb of unused high bits.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct SparseCurve;
+
+    impl Curve for SparseCurve {
+        type Uint = U128;
+
+        const ORDER: U128 = U128::from_u64(1_000_003);
+    }
+
+    fn check<C, W>(a: elliptic_curve::ScalarPrimitive<C>, b: elliptic_curve::ScalarPrimitive<C>)
+    where
+        C: Curve,
+        C::Uint: Concat<Output = W>,
+        W: Integer + Split<Output = C::Uint>,
+    {
+        let a_wide = C::Uint::ZERO.concat(a.as_uint());
+        let b_wide = C::Uint::ZERO.concat(b.as_uint());
+        let product = a_wide.checked_mul(&b_wide).unwrap();
+        assert_eq!(
+            a * b,
+            elliptic_curve::ScalarPrimitive::reduce_wide_uint(product)
+        );
+    }
+
+    fn check_random<C, W>()
+    where
+        C: Curve,
+        C::Uint: Concat<Output = W>,
+        W: Integer + Split<Output = C::Uint>,
+    {
+        type Scalar<C> = elliptic_curve::ScalarPrimitive<C>;
+
+        for _ in 0..64 {
+            check(Scalar::<C>::random(&mut OsRng), Scalar::random(&mut OsRng));
+        }
+
+        // Extremes, where the quotient estimates need the most correction
+        check(-Scalar::<C>::ONE, -Scalar::ONE);
+        check(-Scalar::<C>::ONE, Scalar::ONE);
+    }
+
+    check_random::<MockCurve, _>();
+    check_random::<P256BaseField, _>();
+    check_random::<SmallCurve, _>();
+    check_random::<CompositeCurve, _>();
+    check_random::<SparseCurve, _>();
+}
+
+#[test]
+fn horner_eval() {
+    let x = ScalarPrimitive::from(7u64);
+    let coeffs = [3u64, 5, 2].map(ScalarPrimitive::from);
+
+    // 2x^2 + 5x + 3
+    let expected = ScalarPrimitive::from(2 * 49 + 5 * 7 + 3u64);
+    assert_eq!(ScalarPrimitive::horner_eval(&x, &coeffs), expected);
+
+    // 3x^2 + 5x + 2
+    let expected = ScalarPrimitive::from(3 * 49 + 5 * 7 + 2u64);
+    assert_eq!(
+        ScalarPrimitive::horner_eval_descending(&x, &coeffs),
+        expected
+    );
+
+    // -x^3 + 4x + 1
+    let x = ScalarPrimitive::from(1u64 << 40);
+    let coeffs = [
+        ScalarPrimitive::ONE,
+        ScalarPrimitive::from(4u64),
+        ScalarPrimitive::ZERO,
+        -ScalarPrimitive::ONE,
+    ];
+    let x_cubed = ScalarPrimitive::new(U256::ONE << 120).unwrap();
+    let expected = -x_cubed + ScalarPrimitive::from((4u64 << 40) + 1);
+    assert_eq!(ScalarPrimitive::horner_eval(&x, &coeffs), expected);
+}
+
+#[test]
+fn invert() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    for x in [
+        ScalarPrimitive::ONE,
+        ScalarPrimitive::from(2u64),
+        ScalarPrimitive::from(0x1234_5678_9abc_def0u64),
+        minus_one,
+    ] {
+        assert_eq!(x * x.invert().unwrap(), ScalarPrimitive::ONE);
+    }
+
+    assert_eq!(minus_one.invert().unwrap(), minus_one);
+    assert!(bool::from(ScalarPrimitive::ZERO.invert().is_none()));
+}
+
+#[test]
+fn lagrange_reconstruction() {
+    let secret = ScalarPrimitive::from(0xdead_beefu64);
+    let coeffs = [
+        secret,
+        ScalarPrimitive::from(0x1234u64),
+        ScalarPrimitive::from(0x5678u64),
+    ];
+
+    let indices = [1u64, 2, 5].map(ScalarPrimitive::from);
+    let shares = indices.map(|x| ScalarPrimitive::horner_eval(&x, &coeffs));
+
+    let mut reconstructed = ScalarPrimitive::ZERO;
+    for i in 0..indices.len() {
+        let others: Vec<_> = (0..indices.len())
+            .filter(|&j| j != i)
+            .map(|j| indices[j])
+            .collect();
+        let coeff = ScalarPrimitive::lagrange_coefficient_at_zero(&indices[i], &others).unwrap();
+        reconstructed += shares[i] * coeff;
+    }
+
+    assert_eq!(reconstructed, secret);
+
+    let duplicate = [indices[0], indices[1]];
+    let coeff = ScalarPrimitive::lagrange_coefficient_at_zero(&indices[0], &duplicate);
+    assert!(bool::from(coeff.is_none()));
+}
+
+#[test]
+fn sizes() {
+    const BUF: [u8; ScalarPrimitive::BYTES] = [0; ScalarPrimitive::BYTES];
+    assert_eq!(BUF.len(), 32);
+    assert_eq!(ScalarPrimitive::BITS, 256);
+    assert_eq!(
+        ScalarPrimitive::ONE.to_be_bytes().len(),
+        ScalarPrimitive::BYTES
+    );
+}
+
+#[test]
+fn batch_invert() {
+    let scalars = [3u64, 5, 0x1234_5678, u64::MAX].map(ScalarPrimitive::from);
+    let expected = scalars.map(|s| s.invert().unwrap());
+
+    let mut inverted = scalars;
+    let mut scratch = [ScalarPrimitive::ZERO; 4];
+    assert!(bool::from(
+        ScalarPrimitive::batch_invert_with_scratch(&mut inverted, &mut scratch).is_some()
+    ));
+    assert_eq!(inverted, expected);
+
+    let mut inverted = scalars;
+    assert!(bool::from(
+        ScalarPrimitive::batch_invert(&mut inverted).is_some()
+    ));
+    assert_eq!(inverted, expected);
+
+    let mut with_zero = [scalars[0], ScalarPrimitive::ZERO, scalars[1]];
+    assert!(bool::from(
+        ScalarPrimitive::batch_invert(&mut with_zero).is_none()
+    ));
+    assert_eq!(with_zero, [scalars[0], ScalarPrimitive::ZERO, scalars[1]]);
+}
+
+#[test]
+fn batch_invert_or_zero() {
+    let scalars = [
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::from(3u64),
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap(),
+        ScalarPrimitive::from(0x1234_5678u64),
+        ScalarPrimitive::ZERO,
+    ];
+    let expected = scalars.map(|s| s.invert().unwrap_or(ScalarPrimitive::ZERO));
+
+    let mut inverted = scalars;
+    let mut scratch = [ScalarPrimitive::ZERO; 6];
+    ScalarPrimitive::batch_invert_or_zero_with_scratch(&mut inverted, &mut scratch);
+    assert_eq!(inverted, expected);
+
+    let mut inverted = scalars;
+    ScalarPrimitive::batch_invert_or_zero(&mut inverted);
+    assert_eq!(inverted, expected);
+
+    let mut zeros = [ScalarPrimitive::ZERO; 3];
+    ScalarPrimitive::batch_invert_or_zero(&mut zeros);
+    assert_eq!(zeros, [ScalarPrimitive::ZERO; 3]);
+
+    ScalarPrimitive::batch_invert_or_zero(&mut []);
+}
+
+#[cfg(feature = "hash2curve")]
+mod hash_to_scalar {
+    use super::P256BaseField;
+    use elliptic_curve::{hash2curve::ExpandMsgXmd, ScalarPrimitive};
+    use hex_literal::hex;
+    use sha2::Sha256;
+
+    /// RFC 9380 Appendix J.1.2: P256_XMD:SHA-256_SSWU_NU_
+    #[test]
+    fn rfc9380_vectors() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_";
+
+        let vectors: [(&[u8], [u8; 32]); 2] = [
+            (
+                b"",
+                hex!("b22d487045f80e9edcb0ecc8d4bf77833e2bf1f3a54004d7df1d57f4802d311f"),
+            ),
+            (
+                b"abc",
+                hex!("c7f96eadac763e176629b09ed0c11992225b3a5ae99479760601cbd69c221e58"),
+            ),
+        ];
+
+        for (msg, expected) in vectors {
+            let scalar = ScalarPrimitive::<P256BaseField>::hash_to_scalar::<ExpandMsgXmd<Sha256>>(
+                &[msg],
+                DST,
+            )
+            .unwrap();
+            assert_eq!(scalar.to_be_bytes().as_slice(), &expected);
+        }
+    }
+}
+
+#[test]
+fn to_scalar() {
+    let primitive = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let scalar: Scalar = primitive.to_scalar();
+    assert_eq!(ScalarPrimitive::from(scalar), primitive);
+}
+
+#[test]
+fn from_scalar() {
+    for _ in 0..16 {
+        let scalar = Scalar::random(&mut OsRng);
+        let primitive = ScalarPrimitive::from_scalar(&scalar);
+        assert_eq!(primitive, ScalarPrimitive::from(scalar));
+        assert_eq!(primitive.to_scalar(), scalar);
+    }
+}
+
+#[test]
+fn reduce_mod() {
+    let scalar = ScalarPrimitive::from(1000u64);
+    let modulus = NonZero::new(U256::from(7u64)).unwrap();
+    assert_eq!(scalar.reduce_mod(&modulus), U256::from(1000u64 % 7));
+
+    let modulus = NonZero::new(U256::from(4u64)).unwrap();
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert_eq!(minus_one.reduce_mod(&modulus), U256::ZERO);
+
+    let modulus = NonZero::new(U256::from(2000u64)).unwrap();
+    assert_eq!(scalar.reduce_mod(&modulus), U256::from(1000u64));
+}
+
+#[test]
+fn checked_new() {
+    let modulus = ScalarPrimitive::MODULUS;
+    assert_eq!(
+        ScalarPrimitive::checked_new(U256::ONE),
+        Ok(ScalarPrimitive::ONE)
+    );
+    assert!(ScalarPrimitive::checked_new(modulus.wrapping_sub(&U256::ONE)).is_ok());
+    assert_eq!(ScalarPrimitive::checked_new(modulus), Err(Error));
+    assert_eq!(ScalarPrimitive::checked_new(U256::MAX), Err(Error));
+}
+
+#[test]
+fn read_be() {
+    let mut data = Vec::new();
+    data.extend_from_slice(&ScalarPrimitive::ONE.to_be_bytes());
+    data.extend_from_slice(&MODULUS_MINUS_ONE);
+    data.extend_from_slice(&[0xff; 31]);
+
+    let mut cursor = data.as_slice();
+    assert_eq!(
+        ScalarPrimitive::read_be(&mut cursor),
+        Ok(ScalarPrimitive::ONE)
+    );
+    assert_eq!(
+        ScalarPrimitive::read_be(&mut cursor),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE)
+    );
+    assert_eq!(cursor.len(), 31);
+    assert_eq!(ScalarPrimitive::read_be(&mut cursor), Err(Error));
+    assert_eq!(cursor.len(), 31);
+
+    let mut cursor = &[0xff; 32][..];
+    assert_eq!(ScalarPrimitive::read_be(&mut cursor), Err(Error));
+    assert_eq!(cursor.len(), 32);
+}
+
+#[test]
+fn conditional_reduce() {
+    let modulus = ScalarPrimitive::MODULUS;
+    assert_eq!(
+        ScalarPrimitive::conditional_reduce(U256::from(5u64)),
+        ScalarPrimitive::from(5u64)
+    );
+    assert_eq!(
+        ScalarPrimitive::conditional_reduce(modulus),
+        ScalarPrimitive::ZERO
+    );
+    assert_eq!(
+        ScalarPrimitive::conditional_reduce(modulus.wrapping_add(&U256::from(5u64))),
+        ScalarPrimitive::from(5u64)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "input must be less than 2 * MODULUS")]
+fn conditional_reduce_out_of_range() {
+    elliptic_curve::ScalarPrimitive::<SmallCurve>::conditional_reduce(U64::from(2_000_006u64));
+}
+
+#[test]
+fn debug_assert_canonical() {
+    ScalarPrimitive::ZERO.debug_assert_canonical();
+    ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE)
+        .unwrap()
+        .debug_assert_canonical();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "scalar is not canonical")]
+fn debug_assert_canonical_out_of_range() {
+    ScalarPrimitive::new_unchecked(MockCurve::ORDER).debug_assert_canonical();
+}
+
+#[test]
+fn to_le_digits_padded() {
+    let scalar = ScalarPrimitive::from(0x0102_0304u64);
+
+    let mut exact = [0xaa; 32];
+    scalar.to_le_digits_padded(&mut exact).unwrap();
+    assert_eq!(exact.as_slice(), scalar.to_le_bytes().as_slice());
+
+    let mut longer = [0xaa; 40];
+    scalar.to_le_digits_padded(&mut longer).unwrap();
+    assert_eq!(&longer[..32], scalar.to_le_bytes().as_slice());
+    assert_eq!(&longer[32..], &[0; 8]);
+
+    let mut short = [0xaa; 4];
+    scalar.to_le_digits_padded(&mut short).unwrap();
+    assert_eq!(short, [4, 3, 2, 1]);
+
+    let mut too_short = [0xaa; 3];
+    assert_eq!(scalar.to_le_digits_padded(&mut too_short), Err(Error));
+    assert_eq!(too_short, [0xaa; 3]);
+}
+
+#[test]
+fn pow() {
+    let x = ScalarPrimitive::from(3u64);
+    assert_eq!(x.pow([0u64]), ScalarPrimitive::ONE);
+    assert_eq!(x.pow([5u64]), ScalarPrimitive::from(243u64));
+    assert_eq!(x.pow([40u64, 0]), ScalarPrimitive::from(3u64.pow(40)));
+}
+
+#[test]
+fn invert_fermat() {
+    for x in [2u64, 0x1234_5678, u64::MAX].map(ScalarPrimitive::from) {
+        assert_eq!(x.invert_fermat().unwrap(), x.invert().unwrap());
+    }
+
+    assert!(bool::from(ScalarPrimitive::ZERO.invert_fermat().is_none()));
+}
+
+#[test]
+fn random_signs() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let mut signs = [ScalarPrimitive::ZERO; 64];
+    ScalarPrimitive::random_signs(&mut OsRng, &mut signs);
+
+    for sign in signs {
+        assert!(sign == ScalarPrimitive::ONE || sign == minus_one);
+    }
+}
+
+#[test]
+fn try_random() {
+    struct FailingRng;
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            unimplemented!()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            unimplemented!()
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            unimplemented!()
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            Err(NonZeroU32::new(rand_core::Error::CUSTOM_START)
+                .unwrap()
+                .into())
+        }
+    }
+
+    impl CryptoRng for FailingRng {}
+
+    assert!(ScalarPrimitive::try_random(&mut OsRng).is_ok());
+
+    let err = ScalarPrimitive::try_random(&mut FailingRng).unwrap_err();
+    assert_eq!(err.code(), NonZeroU32::new(rand_core::Error::CUSTOM_START));
+}
+
+#[test]
+fn bits_le() {
+    let scalar = ScalarPrimitive::from(0b1011u64);
+    let bits = scalar.to_bits_le();
+    assert_eq!(bits.len(), ScalarPrimitive::BITS);
+    assert_eq!(
+        bits[..5].iter().map(|b| b.unwrap_u8()).collect::<Vec<_>>(),
+        [1, 1, 0, 1, 0]
+    );
+    assert!(bits[4..].iter().all(|b| b.unwrap_u8() == 0));
+
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    for scalar in [ScalarPrimitive::ZERO, scalar, minus_one] {
+        let bits = scalar.to_bits_le();
+        assert_eq!(ScalarPrimitive::from_bits_le(&bits).unwrap(), scalar);
+    }
+
+    let overflow = [Choice::from(1); 256].into();
+    assert!(bool::from(
+        ScalarPrimitive::from_bits_le(&overflow).is_none()
+    ));
+}
+
+#[test]
+fn assign_from_be_bytes() {
+    let mut scalar = ScalarPrimitive::ONE;
+    assert!(bool::from(
+        scalar
+            .assign_from_be_bytes(&MODULUS_MINUS_ONE.into())
+            .is_some()
+    ));
+    assert_eq!(scalar.to_be_bytes(), MODULUS_MINUS_ONE.into());
+
+    assert!(bool::from(
+        scalar.assign_from_be_bytes(&[0xff; 32].into()).is_none()
+    ));
+    assert_eq!(scalar.to_be_bytes(), MODULUS_MINUS_ONE.into());
+}
+
+#[test]
+fn validate_in_range() {
+    let some = |uint| CtOption::new(uint, Choice::from(1));
+    let none = |uint| CtOption::new(uint, Choice::from(0));
+
+    assert_eq!(
+        ScalarPrimitive::validate_in_range(some(U256::from_u8(42))).unwrap(),
+        ScalarPrimitive::from(42u64)
+    );
+    assert!(bool::from(
+        ScalarPrimitive::validate_in_range(none(U256::from_u8(42))).is_none()
+    ));
+    assert!(bool::from(
+        ScalarPrimitive::validate_in_range(some(U256::MAX)).is_none()
+    ));
+
+    // Chaining with a further constant-time check
+    let nonzero = |uint| {
+        ScalarPrimitive::validate_in_range(some(uint)).and_then(|s| CtOption::new(s, !s.is_zero()))
+    };
+    assert!(bool::from(nonzero(U256::ONE).is_some()));
+    assert!(bool::from(nonzero(U256::ZERO).is_none()));
+}
+
+#[test]
+fn reduce_wide_uint() {
+    let n_minus_one = U256::from_be_slice(&MODULUS_MINUS_ONE);
+    let n_minus_two = n_minus_one.wrapping_sub(&U256::ONE);
+
+    // (n - 1)^2 = 1 (mod n)
+    let (lo, hi) = n_minus_one.mul_wide(&n_minus_one);
+    assert_eq!(
+        ScalarPrimitive::reduce_wide_uint(hi.concat(&lo)),
+        ScalarPrimitive::ONE
+    );
+
+    // (n - 1)(n - 2) = 2 (mod n)
+    let (lo, hi) = n_minus_one.mul_wide(&n_minus_two);
+    assert_eq!(
+        ScalarPrimitive::reduce_wide_uint(hi.concat(&lo)),
+        ScalarPrimitive::from(2u64)
+    );
+
+    assert_eq!(
+        ScalarPrimitive::reduce_wide_uint(U512::from(7u64)),
+        ScalarPrimitive::from(7u64)
+    );
+    // 2^256 (mod n)
+    assert_eq!(
+        ScalarPrimitive::reduce_wide_uint(U256::ONE.concat(&U256::ZERO)),
+        ScalarPrimitive::from(2u64).pow([256u64])
+    );
+}
+
+#[test]
+fn reduce_wide_with() {
+    let ctx = ScalarPrimitive::reduction_context();
+    let n_minus_one = U256::from_be_slice(&MODULUS_MINUS_ONE);
+    let (lo, hi) = n_minus_one.mul_wide(&n_minus_one);
+
+    for wide in [
+        U512::ZERO,
+        U512::from(7u64),
+        U256::ZERO.concat(&n_minus_one),
+        U256::ZERO.concat(&MockCurve::ORDER),
+        U256::ONE.concat(&U256::ZERO),
+        hi.concat(&lo),
+        U512::MAX,
+    ] {
+        assert_eq!(
+            ScalarPrimitive::reduce_wide_with(&ctx, &wide),
+            ScalarPrimitive::reduce_wide_uint(wide)
+        );
+    }
+
+    // Small order with many unused high bits
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    let ctx = Small::reduction_context();
+
+    for wide in [U128::from(1_000_003u64 * 1_000_002), U128::MAX] {
+        assert_eq!(
+            Small::reduce_wide_with(&ctx, &wide),
+            Small::reduce_wide_uint(wide)
+        );
+    }
+}
+
+#[test]
+fn times_cofactor_is_zero() {
+    type Scalar = elliptic_curve::ScalarPrimitive<CompositeCurve>;
+    let cofactor = U64::from_u8(8);
+
+    assert!(bool::from(Scalar::ZERO.times_cofactor_is_zero(&cofactor)));
+    assert!(bool::from(
+        Scalar::from(1_000_003u64).times_cofactor_is_zero(&cofactor)
+    ));
+    assert!(bool::from(
+        Scalar::from(3 * 1_000_003u64).times_cofactor_is_zero(&cofactor)
+    ));
+    assert!(!bool::from(Scalar::ONE.times_cofactor_is_zero(&cofactor)));
+    assert!(!bool::from(
+        Scalar::from(500_001u64).times_cofactor_is_zero(&cofactor)
+    ));
+
+    // Cofactors larger than the order are reduced first
+    let cofactor = CompositeCurve::ORDER.wrapping_add(&cofactor);
+    assert!(bool::from(
+        Scalar::from(1_000_003u64).times_cofactor_is_zero(&cofactor)
+    ));
+}
+
+#[test]
+fn pow_bounded() {
+    let base = ScalarPrimitive::from(3u64);
+
+    for exp in [0u64, 1, 2, 5, 255, 0xffff] {
+        assert_eq!(
+            base.pow_bounded(&ScalarPrimitive::from(exp), 16),
+            base.pow([exp])
+        );
+    }
+
+    let exp = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert_eq!(
+        base.pow_bounded(&exp, 256),
+        base.pow(exp.as_uint().to_words())
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "exponent does not fit in the given bit length")]
+fn pow_bounded_exponent_too_long() {
+    ScalarPrimitive::ONE.pow_bounded(&ScalarPrimitive::from(0x1_0000u64), 16);
+}
+
+#[test]
+fn div() {
+    let x = ScalarPrimitive::from(42u64);
+    let y = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(x / x, ScalarPrimitive::ONE);
+    assert_eq!(y / y, ScalarPrimitive::ONE);
+    assert_eq!((x / y) * y, x);
+    assert_eq!(x / ScalarPrimitive::from(6u64), ScalarPrimitive::from(7u64));
+
+    let mut z = x;
+    z /= ScalarPrimitive::from(2u64);
+    z /= &ScalarPrimitive::from(3u64);
+    assert_eq!(z, ScalarPrimitive::from(7u64));
+
+    assert!(bool::from(x.checked_div(&ScalarPrimitive::ZERO).is_none()));
+    assert_eq!(
+        ScalarPrimitive::ZERO.checked_div(&x).unwrap(),
+        ScalarPrimitive::ZERO
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_by_zero() {
+    let _ = ScalarPrimitive::ONE / ScalarPrimitive::ZERO;
+}
+
+#[test]
+fn montgomery_words() {
+    // R = 2^256 mod n
+    let r = [
+        0x0c46353d039cdaaf,
+        0x4319055258e8617b,
+        0x0000000000000000,
+        0x00000000ffffffff,
+    ];
+    assert_eq!(ScalarPrimitive::ONE.to_montgomery_words().as_slice(), r);
+    assert_eq!(
+        ScalarPrimitive::from_montgomery_words(&r.into()).unwrap(),
+        ScalarPrimitive::ONE
+    );
+
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    for scalar in [
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::from(42u64),
+        minus_one,
+    ] {
+        let words = scalar.to_montgomery_words();
+        assert_eq!(
+            ScalarPrimitive::from_montgomery_words(&words).unwrap(),
+            scalar
+        );
+    }
+
+    let overflow = GenericArray::from([u64::MAX; 4]);
+    assert!(bool::from(
+        ScalarPrimitive::from_montgomery_words(&overflow).is_none()
+    ));
+
+    for _ in 0..16 {
+        let scalar = ScalarPrimitive::random(&mut OsRng);
+        let words = scalar.to_montgomery_words();
+        assert_eq!(
+            ScalarPrimitive::from_montgomery_words(&words).unwrap(),
+            scalar
+        );
+    }
+
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    let r = [350687];
+    assert_eq!(Small::ONE.to_montgomery_words().as_slice(), r);
+    assert_eq!(Small::from_montgomery_words(&r.into()).unwrap(), Small::ONE);
+    let x = Small::from(123_456u64);
+    assert_eq!(
+        Small::from_montgomery_words(&x.to_montgomery_words()).unwrap(),
+        x
+    );
+
+    // `R` isn't invertible modulo an even order
+    type Composite = elliptic_curve::ScalarPrimitive<CompositeCurve>;
+    let words = Composite::ONE.to_montgomery_words();
+    assert_eq!(words.as_slice(), [3350696]);
+    assert!(bool::from(
+        Composite::from_montgomery_words(&words).is_none()
+    ));
+}
+
+#[test]
+fn is_canonical_le() {
+    let mut bytes = MODULUS_MINUS_ONE;
+    bytes.reverse();
+    assert!(bool::from(ScalarPrimitive::is_canonical_le(&bytes.into())));
+
+    // Exactly the modulus
+    bytes[0] += 1;
+    assert!(!bool::from(ScalarPrimitive::is_canonical_le(&bytes.into())));
+
+    assert!(bool::from(ScalarPrimitive::is_canonical_le(
+        &Default::default()
+    )));
+    assert!(!bool::from(ScalarPrimitive::is_canonical_le(
+        &[0xff; 32].into()
+    )));
+}
+
+#[test]
+fn fold_bits_le() {
+    let count = |scalar: ScalarPrimitive| {
+        scalar.fold_bits_le(0u32, |acc, bit| acc + u32::from(bit.unwrap_u8()))
+    };
+
+    assert_eq!(count(ScalarPrimitive::ZERO), 0);
+    assert_eq!(count(ScalarPrimitive::from(0b1011u64)), 3);
+    assert_eq!(
+        count(ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap()),
+        U256::from_be_slice(&MODULUS_MINUS_ONE)
+            .to_words()
+            .iter()
+            .map(|w| w.count_ones())
+            .sum::<u32>()
+    );
+
+    let visited = ScalarPrimitive::ONE.fold_bits_le(0usize, |acc, _| acc + 1);
+    assert_eq!(visited, ScalarPrimitive::BITS);
+}
+
+#[cfg(feature = "borsh")]
+mod borsh {
+    use super::{ScalarPrimitive, MODULUS_MINUS_ONE};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    #[test]
+    fn round_trip() {
+        let scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+        let encoded = scalar.try_to_vec().unwrap();
+        assert_eq!(encoded, scalar.to_le_bytes().as_slice());
+        assert_eq!(ScalarPrimitive::try_from_slice(&encoded).unwrap(), scalar);
+    }
+
+    #[test]
+    fn reject_overflow() {
+        let mut bytes = MODULUS_MINUS_ONE;
+        bytes.reverse();
+        bytes[0] += 1;
+        assert!(ScalarPrimitive::try_from_slice(&bytes).is_err());
+        assert!(ScalarPrimitive::try_from_slice(&[0u8; 31]).is_err());
+    }
+}
+
+#[test]
+fn random_reduce() {
+    type Scalar = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    const SAMPLES: u64 = 10_000;
+
+    let order = SmallCurve::ORDER.as_words()[0];
+    let mut sum = 0u64;
+    let mut odd = 0u64;
+
+    for _ in 0..SAMPLES {
+        let scalar = Scalar::random_reduce(&mut OsRng);
+        let value = scalar.as_uint().as_words()[0];
+        assert!(value < order);
+        sum += value;
+        odd += u64::from(scalar.is_odd().unwrap_u8());
+    }
+
+    // The mean and parity should be close to those of a uniform distribution
+    let mean = sum / SAMPLES;
+    assert!((order * 45 / 100..order * 55 / 100).contains(&mean));
+    assert!((SAMPLES * 45 / 100..SAMPLES * 55 / 100).contains(&odd));
+
+    let scalar = ScalarPrimitive::random_reduce(&mut OsRng);
+    assert!(bool::from(ScalarPrimitive::new(scalar.to_uint()).is_some()));
+}
+
+#[test]
+fn conditional_swap() {
+    let one = ScalarPrimitive::ONE;
+    let two = ScalarPrimitive::from(2u64);
+
+    let (mut a, mut b) = (one, two);
+    ScalarPrimitive::conditional_swap(&mut a, &mut b, Choice::from(0));
+    assert_eq!((a, b), (one, two));
+
+    ScalarPrimitive::conditional_swap(&mut a, &mut b, Choice::from(1));
+    assert_eq!((a, b), (two, one));
+}
+
+#[test]
+fn pow_vartime() {
+    let base = ScalarPrimitive::from(7u64);
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    for exp in [
+        &[0u64][..],
+        &[1],
+        &[0xdead_beef],
+        &[u64::MAX, 1],
+        &minus_one.as_uint().to_words(),
+    ] {
+        assert_eq!(base.pow_vartime(exp), base.pow(exp));
+    }
+}
+
+#[test]
+fn pow_vartime_table() {
+    let base = ScalarPrimitive::from(7u64);
+    let exps = [
+        vec![0u64],
+        vec![1],
+        vec![0xdead_beef],
+        vec![u64::MAX, 1],
+        vec![],
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE)
+            .unwrap()
+            .as_uint()
+            .to_words()
+            .to_vec(),
+    ];
+
+    for window in [1, 3, 4, 8] {
+        let table = base.precompute_pow_table(window);
+        assert_eq!(table.window(), window);
+
+        for exp in &exps {
+            assert_eq!(
+                ScalarPrimitive::pow_vartime_table(&table, exp),
+                base.pow_vartime(exp)
+            );
+        }
+    }
+}
+
+#[test]
+fn pow_table_sizing() {
+    assert_eq!(ScalarPrimitive::pow_table_len(1), 2);
+    assert_eq!(ScalarPrimitive::pow_table_len(4), 16);
+    assert_eq!(ScalarPrimitive::pow_table_len(8), 256);
+
+    assert_eq!(ScalarPrimitive::recommended_window(0), 1);
+    assert_eq!(ScalarPrimitive::recommended_window(1), 4);
+    assert_eq!(ScalarPrimitive::recommended_window(10), 6);
+    assert_eq!(ScalarPrimitive::recommended_window(1000), 8);
+    assert_eq!(ScalarPrimitive::recommended_window(usize::MAX), 8);
+
+    // Shorter exponents favor smaller tables
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    assert_eq!(Small::recommended_window(1), 3);
+
+    let window = ScalarPrimitive::recommended_window(10);
+    let table = ScalarPrimitive::ONE.precompute_pow_table(window);
+    assert_eq!(table.window(), window);
+}
+
+#[test]
+#[should_panic(expected = "window must be in 1..=8")]
+fn pow_table_len_out_of_range() {
+    ScalarPrimitive::pow_table_len(9);
+}
+
+#[test]
+fn new_reduced_flagged() {
+    let n_minus_one = U256::from_be_slice(&MODULUS_MINUS_ONE);
+
+    let (scalar, canonical) = ScalarPrimitive::new_reduced_flagged(n_minus_one);
+    assert_eq!(scalar.to_uint(), n_minus_one);
+    assert!(bool::from(canonical));
+
+    let (scalar, canonical) =
+        ScalarPrimitive::new_reduced_flagged(n_minus_one.wrapping_add(&U256::ONE));
+    assert_eq!(scalar, ScalarPrimitive::ZERO);
+    assert!(!bool::from(canonical));
+
+    let (scalar, canonical) = ScalarPrimitive::new_reduced_flagged(U256::MAX);
+    assert_eq!(
+        scalar,
+        ScalarPrimitive::new(
+            U256::MAX
+                .wrapping_sub(&n_minus_one)
+                .wrapping_sub(&U256::ONE)
+        )
+        .unwrap()
+    );
+    assert!(!bool::from(canonical));
+
+    // Inputs which need more than one subtraction of the modulus
+    let (scalar, canonical) =
+        elliptic_curve::ScalarPrimitive::<SmallCurve>::new_reduced_flagged(U64::MAX);
+    assert_eq!(scalar.to_uint(), U64::from(u64::MAX % 1_000_003));
+    assert!(!bool::from(canonical));
+}
+
+#[test]
+fn dot_product() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let a = [minus_one, ScalarPrimitive::from(2u64), minus_one];
+    let b = [
+        minus_one,
+        ScalarPrimitive::from(3u64),
+        ScalarPrimitive::from(5u64),
+    ];
+
+    let expected = a
+        .iter()
+        .zip(&b)
+        .fold(ScalarPrimitive::ZERO, |acc, (x, y)| x.mul_add(y, &acc));
+    assert_eq!(ScalarPrimitive::dot_product(&a, &b).unwrap(), expected);
+    assert_eq!(expected, ScalarPrimitive::from(2u64));
+
+    assert_eq!(
+        ScalarPrimitive::dot_product(&[], &[]).unwrap(),
+        ScalarPrimitive::ZERO
+    );
+    assert!(bool::from(
+        ScalarPrimitive::dot_product(&a, &b[..2]).is_none()
+    ));
+
+    // Many near-modulus terms to exercise wide accumulation
+    let a = [minus_one; 64];
+    assert_eq!(
+        ScalarPrimitive::dot_product(&a, &a).unwrap(),
+        ScalarPrimitive::from(64u64)
+    );
+
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    let a: Vec<Small> = (0..17).map(|_| Small::random(&mut OsRng)).collect();
+    let b: Vec<Small> = (0..17).map(|_| Small::random(&mut OsRng)).collect();
+    let expected = a
+        .iter()
+        .zip(&b)
+        .fold(Small::ZERO, |acc, (x, y)| x.mul_add(y, &acc));
+    assert_eq!(Small::dot_product(&a, &b).unwrap(), expected);
+}
+
+#[test]
+fn new_unchecked() {
+    const MINUS_ONE: ScalarPrimitive =
+        ScalarPrimitive::new_unchecked(U256::from_be_slice(&MODULUS_MINUS_ONE));
+
+    assert!(bool::from(
+        ScalarPrimitive::new(MINUS_ONE.to_uint()).is_some()
+    ));
+    assert_eq!(MINUS_ONE, -ScalarPrimitive::ONE);
+}
+
+#[test]
+fn map_into() {
+    // The P-256 base field modulus is larger than the group order
+    let scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let mapped = scalar.map_into::<P256BaseField>().unwrap();
+    assert_eq!(mapped.to_uint(), scalar.to_uint());
+    assert_eq!(mapped.map_into::<MockCurve>().unwrap(), scalar);
+
+    let too_large = -elliptic_curve::ScalarPrimitive::<P256BaseField>::ONE;
+    assert!(bool::from(too_large.map_into::<MockCurve>().is_none()));
+}
+
+#[test]
+fn mul_small() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    for scalar in [
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::from(42u64),
+        minus_one,
+    ] {
+        for k in [0, 1, 2, 5, 255, u32::MAX] {
+            assert_eq!(
+                scalar.mul_small(k),
+                scalar * ScalarPrimitive::from(u64::from(k))
+            );
+        }
+    }
+}
+
+mod checksummed {
+    use super::{ScalarPrimitive, MODULUS_MINUS_ONE};
+    use elliptic_curve::Error;
+
+    const ONE: &str = "scalar1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqsnygg8h";
+    const MINUS_ONE: &str = "scalar1llllllcqqqqqpllllllllllll77wd74d5uteap8nh89v9lrry4gqcjzw3f";
+
+    #[test]
+    fn round_trip() {
+        let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+        for (scalar, encoded) in [(ScalarPrimitive::ONE, ONE), (minus_one, MINUS_ONE)] {
+            // Within the length for which Bech32's error detection holds
+            assert!(encoded.len() <= 90);
+            assert_eq!(scalar.to_base32_checksummed(), encoded);
+            assert_eq!(
+                ScalarPrimitive::from_base32_checksummed(encoded),
+                Ok(scalar)
+            );
+            assert_eq!(
+                ScalarPrimitive::from_base32_checksummed(&encoded.to_uppercase()),
+                Ok(scalar)
+            );
+        }
+    }
+
+    #[test]
+    fn reject_corrupted() {
+        // Substituted data and checksum characters
+        let corrupted = ONE.replacen("qqq", "qpq", 1);
+        assert_eq!(
+            ScalarPrimitive::from_base32_checksummed(&corrupted),
+            Err(Error)
+        );
+        let corrupted = ONE.replace("8h", "8g");
+        assert_eq!(
+            ScalarPrimitive::from_base32_checksummed(&corrupted),
+            Err(Error)
+        );
+
+        // Mixed case, wrong prefix and truncation
+        let mixed = ONE.replacen('q', "Q", 1);
+        assert_eq!(ScalarPrimitive::from_base32_checksummed(&mixed), Err(Error));
+        let prefix = ONE.replacen("scalar", "scalaz", 1);
+        assert_eq!(
+            ScalarPrimitive::from_base32_checksummed(&prefix),
+            Err(Error)
+        );
+        assert_eq!(
+            ScalarPrimitive::from_base32_checksummed(&ONE[..ONE.len() - 1]),
+            Err(Error)
+        );
+    }
+
+    #[test]
+    fn reject_overflow() {
+        // Valid checksum over 32 bytes of `0xff`
+        let encoded = "scalar1lllllllllllllllllllllllllllllllllllllllllllllllllllsymrpmn";
+        assert_eq!(
+            ScalarPrimitive::from_base32_checksummed(encoded),
+            Err(Error)
+        );
+    }
+}
+
+#[test]
+fn batch_from_be_slice() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let scalars = [
+        ScalarPrimitive::ONE,
+        minus_one,
+        ScalarPrimitive::from(42u64),
+    ];
+    let data = scalars
+        .iter()
+        .flat_map(|s| s.to_be_bytes())
+        .collect::<Vec<u8>>();
+
+    assert_eq!(
+        ScalarPrimitive::batch_from_be_slice(&data),
+        Ok(scalars.to_vec())
+    );
+    assert_eq!(ScalarPrimitive::batch_from_be_slice(&[]), Ok(vec![]));
+    assert_eq!(ScalarPrimitive::batch_from_be_slice(&data[1..]), Err(Error));
+
+    let mut data = data;
+    data[32..64].fill(0xff);
+    assert_eq!(ScalarPrimitive::batch_from_be_slice(&data), Err(Error));
+
+    // Large enough to be decoded in parallel with the `rayon` feature
+    let scalars: Vec<_> = (0..2048u64).map(ScalarPrimitive::from).collect();
+    let mut data: Vec<u8> = scalars.iter().flat_map(|s| s.to_be_bytes()).collect();
+    assert_eq!(ScalarPrimitive::batch_from_be_slice(&data), Ok(scalars));
+    data[2000 * 32..2001 * 32].fill(0xff);
+    assert_eq!(ScalarPrimitive::batch_from_be_slice(&data), Err(Error));
+}
+
+#[test]
+fn ct_in_range() {
+    let lo = ScalarPrimitive::from(10u64);
+    let hi = ScalarPrimitive::from(20u64);
+    let in_range = |n: u64| bool::from(ScalarPrimitive::from(n).ct_in_range(&lo, &hi));
+
+    assert!(!in_range(9));
+    assert!(in_range(10));
+    assert!(in_range(15));
+    assert!(in_range(20));
+    assert!(!in_range(21));
+
+    // Degenerate ranges
+    assert!(bool::from(lo.ct_in_range(&lo, &lo)));
+    assert!(!bool::from(lo.ct_in_range(&hi, &lo)));
+    assert!(!bool::from(hi.ct_in_range(&hi, &lo)));
+}
+
+mod glv {
+    use elliptic_curve::{bigint::U256, scalar::GlvBasis, Curve, ScalarPrimitive};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Curve with the secp256k1 group order, which has an efficiently
+    /// computable endomorphism.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct Secp256k1Order;
+
+    impl Curve for Secp256k1Order {
+        type Uint = U256;
+
+        const ORDER: U256 =
+            U256::from_be_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+    }
+
+    type Scalar = ScalarPrimitive<Secp256k1Order>;
+
+    fn scalar(bytes: [u8; 32]) -> Scalar {
+        Scalar::from_be_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn reconstruction() {
+        let lambda = scalar(hex!(
+            "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72"
+        ));
+        let a1 = scalar(hex!(
+            "000000000000000000000000000000003086d221a7d46bcde86c90e49284eb15"
+        ));
+        let basis = GlvBasis {
+            a1,
+            b1: -scalar(hex!(
+                "00000000000000000000000000000000e4437ed6010e88286f547fa90abfe4c3"
+            )),
+            a2: scalar(hex!(
+                "0000000000000000000000000000000114ca50f7a8e2f3f657c1108d9d44cfd8"
+            )),
+            b2: a1,
+        };
+
+        let mut inputs = vec![Scalar::ZERO, Scalar::ONE, -Scalar::ONE, lambda];
+        inputs.extend((0..32).map(|_| Scalar::random(&mut OsRng)));
+
+        for k in inputs {
+            let (k1_neg, k1, k2_neg, k2) = k.decompose_glv(&basis);
+            let k1_signed = if k1_neg { -k1 } else { k1 };
+            let k2_signed = if k2_neg { -k2 } else { k2 };
+            assert_eq!(k1_signed + k2_signed * lambda, k);
+
+            // Both halves should be at most 129 bits long
+            for half in [k1, k2] {
+                assert!(half.to_be_bytes()[..15].iter().all(|&b| b == 0));
+            }
+        }
+    }
+}
+
+#[test]
+fn from_be_bytes_masked() {
+    let bytes = [0xff; 32];
+
+    // X25519-style clamping
+    let mut clamped = bytes;
+    clamped[31] &= 0xf8;
+    clamped[0] &= 0x7f;
+    clamped[0] |= 0x40;
+    let (expected, _) = ScalarPrimitive::new_reduced_flagged(U256::from_be_slice(&clamped));
+    assert_eq!(
+        ScalarPrimitive::from_be_bytes_masked(bytes.into(), 3, 1, Some(254)),
+        expected
+    );
+
+    // No masking is equivalent to reduction
+    let (expected, _) = ScalarPrimitive::new_reduced_flagged(U256::MAX);
+    assert_eq!(
+        ScalarPrimitive::from_be_bytes_masked(bytes.into(), 0, 0, None),
+        expected
+    );
+
+    assert_eq!(
+        ScalarPrimitive::from_be_bytes_masked(bytes.into(), 200, 56, Some(0)),
+        ScalarPrimitive::ONE
+    );
+}
+
+#[test]
+fn edit() {
+    let mut scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    {
+        let mut guard = scalar.edit();
+        assert_eq!(*guard, U256::from_be_slice(&MODULUS_MINUS_ONE));
+
+        // n - 1 + 3 = n + 2
+        *guard = guard.wrapping_add(&U256::from_u8(3));
+        assert!(*guard > U256::from_be_slice(&MODULUS_MINUS_ONE));
+    }
+
+    assert_eq!(scalar, ScalarPrimitive::from(2u64));
+
+    // Values requiring more than one subtraction of the modulus
+    *scalar.edit() = U256::MAX;
+    assert_eq!(scalar, ScalarPrimitive::new_reduced_flagged(U256::MAX).0);
+}
+
+#[test]
+fn str_radix() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(ScalarPrimitive::ZERO.to_str_radix(10), "0");
+    assert_eq!(ScalarPrimitive::from(255u64).to_str_radix(2), "11111111");
+    assert_eq!(ScalarPrimitive::from(35u64).to_str_radix(36), "z");
+    assert_eq!(
+        minus_one.to_str_radix(16),
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550"
+    );
+    assert_eq!(
+        minus_one.to_str_radix(10),
+        "115792089210356248762697446949407573529996955224135760342422259061068512044368"
+    );
+
+    for scalar in [
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::from(42u64),
+        minus_one,
+    ] {
+        for radix in [2, 10, 16, 32, 36] {
+            let s = scalar.to_str_radix(radix);
+            assert_eq!(ScalarPrimitive::from_str_radix(&s, radix), Ok(scalar));
+            assert_eq!(
+                ScalarPrimitive::from_str_radix(&s.to_uppercase(), radix),
+                Ok(scalar)
+            );
+        }
+    }
+
+    assert_eq!(ScalarPrimitive::from_str_radix("10", 1), Err(Error));
+    assert_eq!(ScalarPrimitive::from_str_radix("10", 37), Err(Error));
+    assert_eq!(ScalarPrimitive::from_str_radix("", 10), Err(Error));
+    assert_eq!(ScalarPrimitive::from_str_radix("12a", 10), Err(Error));
+    assert_eq!(
+        ScalarPrimitive::from_str_radix("+ff", 16),
+        Ok(ScalarPrimitive::from(255u64))
+    );
+    assert_eq!(ScalarPrimitive::from_str_radix("+", 10), Err(Error));
+    assert_eq!(ScalarPrimitive::from_str_radix("++1", 10), Err(Error));
+    assert_eq!(ScalarPrimitive::from_str_radix("-1", 10), Err(Error));
+    assert_eq!(
+        ScalarPrimitive::from_str_radix(&"z".repeat(60), 36),
+        Err(Error)
+    );
+    assert_eq!(
+        ScalarPrimitive::from_str_radix(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            16
+        ),
+        Err(Error)
+    );
+}
+
+#[test]
+fn ct_eq_const() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let two = ScalarPrimitive::from(2u64);
+
+    assert!(bool::from(two.ct_eq_const(&ScalarPrimitive::from(2u64))));
+    assert!(!bool::from(two.ct_eq_const(&ScalarPrimitive::ONE)));
+
+    assert!(bool::from(ScalarPrimitive::ONE.is_one()));
+    assert!(!bool::from(ScalarPrimitive::ZERO.is_one()));
+    assert!(!bool::from(minus_one.is_one()));
+
+    assert!(bool::from(minus_one.is_modulus_minus_one()));
+    assert!(!bool::from(ScalarPrimitive::ONE.is_modulus_minus_one()));
+    assert!(!bool::from(ScalarPrimitive::ZERO.is_modulus_minus_one()));
+}
+
+#[cfg(feature = "der")]
+mod der {
+    use super::{ScalarPrimitive, MODULUS_MINUS_ONE};
+    use elliptic_curve::{der::Encode, Error};
+    use hex_literal::hex;
+
+    fn encode(scalar: &ScalarPrimitive) -> Vec<u8> {
+        let mut buf = Default::default();
+        scalar.to_der_integer(&mut buf).unwrap().to_vec().unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+        // The high bit is set, so a leading zero byte is required
+        let encoded = encode(&minus_one);
+        assert_eq!(&encoded[..3], &[0x02, 0x21, 0x00]);
+        assert_eq!(&encoded[3..], &MODULUS_MINUS_ONE);
+        assert_eq!(ScalarPrimitive::from_der_integer(&encoded), Ok(minus_one));
+
+        for scalar in [ScalarPrimitive::ZERO, ScalarPrimitive::from(0x7fu64)] {
+            let encoded = encode(&scalar);
+            assert_eq!(encoded.len(), 3);
+            assert_eq!(ScalarPrimitive::from_der_integer(&encoded), Ok(scalar));
+        }
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // Negative
+        assert_eq!(
+            ScalarPrimitive::from_der_integer(&hex!("020180")),
+            Err(Error)
+        );
+
+        // Non-minimal
+        assert_eq!(
+            ScalarPrimitive::from_der_integer(&hex!("02020001")),
+            Err(Error)
+        );
+
+        // Equal to the modulus
+        let mut encoded = Vec::from(hex!("022100"));
+        encoded.extend_from_slice(&MODULUS_MINUS_ONE);
+        *encoded.last_mut().unwrap() += 1;
+        assert_eq!(ScalarPrimitive::from_der_integer(&encoded), Err(Error));
+    }
+}
+
+#[cfg(feature = "jwk")]
+mod jwk {
+    use super::{ScalarPrimitive, MODULUS_MINUS_ONE};
+    use elliptic_curve::Error;
+
+    const MODULUS_MINUS_ONE_BASE64URL: &str = "_____wAAAAD__________7zm-q2nF56E87nKwvxjJVA";
+
+    #[test]
+    fn round_trip() {
+        let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+        assert_eq!(minus_one.to_base64url(), MODULUS_MINUS_ONE_BASE64URL);
+        assert_eq!(
+            ScalarPrimitive::from_base64url(MODULUS_MINUS_ONE_BASE64URL),
+            Ok(minus_one)
+        );
+
+        // Leading zeros are kept
+        let one = ScalarPrimitive::ONE.to_base64url();
+        assert_eq!(one, format!("{}E", "A".repeat(42)));
+        assert_eq!(
+            ScalarPrimitive::from_base64url(&one),
+            Ok(ScalarPrimitive::ONE)
+        );
+    }
+
+    #[test]
+    fn reject_wrong_length() {
+        // 31 and 33 bytes
+        assert_eq!(ScalarPrimitive::from_base64url(&"A".repeat(42)), Err(Error));
+        assert_eq!(ScalarPrimitive::from_base64url(&"A".repeat(44)), Err(Error));
+        assert_eq!(ScalarPrimitive::from_base64url(""), Err(Error));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // Padding and non-URL-safe characters
+        assert_eq!(
+            ScalarPrimitive::from_base64url(&format!("{MODULUS_MINUS_ONE_BASE64URL}=")),
+            Err(Error)
+        );
+        assert_eq!(
+            ScalarPrimitive::from_base64url(&MODULUS_MINUS_ONE_BASE64URL.replace('_', "/")),
+            Err(Error)
+        );
+
+        // Out of range
+        assert_eq!(
+            ScalarPrimitive::from_base64url(&format!("{}w", "_".repeat(42))),
+            Err(Error)
+        );
+    }
+}
+
+#[cfg(feature = "base58")]
+mod base58check {
+    use super::{ScalarPrimitive, MODULUS_MINUS_ONE};
+    use elliptic_curve::Error;
+    use hex_literal::hex;
+
+    /// Uncompressed Bitcoin wallet import format example.
+    const WIF: &str = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+    const WIF_KEY: [u8; 32] =
+        hex!("0C28FCA386C7A227600B2FE50B7CAE11EC86D3BF1FBE471BE89827E19D72AA1D");
+    const WIF_VERSION: u8 = 0x80;
+
+    #[test]
+    fn round_trip() {
+        let key = ScalarPrimitive::from_be_slice(&WIF_KEY).unwrap();
+        assert_eq!(key.to_base58check(WIF_VERSION), WIF);
+        assert_eq!(
+            ScalarPrimitive::from_base58check(WIF),
+            Ok((WIF_VERSION, key))
+        );
+
+        // Leading zero bytes are preserved
+        let zero = ScalarPrimitive::ZERO.to_base58check(0);
+        assert_eq!(zero, "1111111111111111111111111111111112m1s9K");
+        assert_eq!(
+            ScalarPrimitive::from_base58check(&zero),
+            Ok((0, ScalarPrimitive::ZERO))
+        );
+
+        let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+        let encoded = minus_one.to_base58check(0xef);
+        assert_eq!(
+            ScalarPrimitive::from_base58check(&encoded),
+            Ok((0xef, minus_one))
+        );
+    }
+
+    #[test]
+    fn reject_bad_checksum() {
+        // Last character changed
+        let mut wif = String::from(&WIF[..WIF.len() - 1]);
+        wif.push('K');
+        assert_eq!(ScalarPrimitive::from_base58check(&wif), Err(Error));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        // Invalid character
+        assert_eq!(
+            ScalarPrimitive::from_base58check(&WIF.replace('H', "0")),
+            Err(Error)
+        );
+
+        // Truncated
+        assert_eq!(ScalarPrimitive::from_base58check(&WIF[1..]), Err(Error));
+        assert_eq!(ScalarPrimitive::from_base58check(""), Err(Error));
+
+        // Out of range
+        let too_large = -elliptic_curve::ScalarPrimitive::<super::P256BaseField>::ONE;
+        assert_eq!(
+            ScalarPrimitive::from_base58check(&too_large.to_base58check(0)),
+            Err(Error)
+        );
+    }
+}
+
+#[test]
+fn hamming_weight() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(ScalarPrimitive::ZERO.hamming_weight(), 0);
+    assert_eq!(ScalarPrimitive::from(7u64).hamming_weight(), 3);
+    assert_eq!(ScalarPrimitive::from(11u64).hamming_weight(), 3);
+    assert_eq!(minus_one.hamming_weight(), 166);
+}
+
+#[test]
+fn wnaf_weight() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let weights = |scalar: ScalarPrimitive| [2, 3, 4, 5].map(|w| scalar.wnaf_weight(w));
+
+    assert_eq!(weights(ScalarPrimitive::ZERO), [0, 0, 0, 0]);
+    assert_eq!(weights(ScalarPrimitive::from(7u64)), [2, 2, 1, 1]);
+    assert_eq!(weights(ScalarPrimitive::from(11u64)), [3, 2, 2, 1]);
+    assert_eq!(weights(minus_one), [48, 33, 28, 26]);
+}
+
+#[test]
+fn from_hex_detailed() {
+    let hex = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550";
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(ScalarPrimitive::from_hex_detailed(hex), Ok(minus_one));
+    assert_eq!(
+        ScalarPrimitive::from_hex_detailed(&hex.to_uppercase()),
+        Ok(minus_one)
+    );
+
+    assert_eq!(
+        ScalarPrimitive::from_hex_detailed(&hex.replace('c', "g")),
+        Err(ScalarParseError::InvalidHex)
+    );
+    assert_eq!(
+        ScalarPrimitive::from_hex_detailed(&hex[2..]),
+        Err(ScalarParseError::WrongLength)
+    );
+    assert_eq!(
+        ScalarPrimitive::from_hex_detailed(""),
+        Err(ScalarParseError::WrongLength)
+    );
+    assert_eq!(
+        ScalarPrimitive::from_hex_detailed(&"f".repeat(64)),
+        Err(ScalarParseError::OutOfRange)
+    );
+}
+
+#[test]
+fn from_be_hex_fixed() {
+    let hex = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550";
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(ScalarPrimitive::from_be_hex_fixed(hex), Ok(minus_one));
+    assert_eq!(
+        ScalarPrimitive::from_be_hex_fixed(&"0".repeat(63)),
+        Err(Error)
+    );
+    assert_eq!(
+        ScalarPrimitive::from_be_hex_fixed(&"0".repeat(65)),
+        Err(Error)
+    );
+    assert_eq!(
+        ScalarPrimitive::from_be_hex_fixed(&"0".repeat(64)),
+        Ok(ScalarPrimitive::ZERO)
+    );
+}
+
+#[test]
+fn mul_with() {
+    let base = ScalarPrimitive::from(0x1234_5678u64);
+    let add = |a: &ScalarPrimitive, b: &ScalarPrimitive| *a + b;
+    let double = |a: &ScalarPrimitive| *a + a;
+
+    for k in [
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::ONE,
+        ScalarPrimitive::from(0xdead_beefu64),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap(),
+    ] {
+        assert_eq!(
+            k.mul_with(ScalarPrimitive::ZERO, add, double, &base),
+            k * base
+        );
+    }
+
+    // Within `u64`, the result matches integer multiplication
+    let k = ScalarPrimitive::from(1000u64);
+    let product = k.mul_with(0u64, |a, b| a.wrapping_add(*b), |a| a.wrapping_mul(2), &7);
+    assert_eq!(product, 7000);
+}
+
+#[test]
+fn to_be_bytes_padded_to() {
+    let scalar = elliptic_curve::ScalarPrimitive::<SmallCurve>::from(0x0102_0304u64);
+    let padded = scalar.to_be_bytes_padded_to::<32>();
+
+    assert_eq!(padded[..24], [0; 24]);
+    assert_eq!(padded[24..], scalar.to_be_bytes()[..]);
+    assert_eq!(
+        scalar.to_be_bytes_padded_to::<8>()[..],
+        scalar.to_be_bytes()[..]
+    );
+
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert_eq!(minus_one.to_be_bytes_padded_to::<32>(), MODULUS_MINUS_ONE);
+}
+
+#[test]
+#[should_panic]
+fn to_be_bytes_padded_to_too_short() {
+    ScalarPrimitive::ONE.to_be_bytes_padded_to::<16>();
+}
+
+#[test]
+fn from_bytes_mod_order_nonzero() {
+    let order = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+    assert!(bool::from(
+        ScalarPrimitive::from_bytes_mod_order_nonzero(&order).is_none()
+    ));
+    assert!(bool::from(
+        ScalarPrimitive::from_bytes_mod_order_nonzero(&[]).is_none()
+    ));
+
+    // `256 * n` is a multiple of the order which doesn't fit in `BYTES`
+    let mut shifted_order = [0u8; 33];
+    shifted_order[..32].copy_from_slice(&order);
+    assert!(bool::from(
+        ScalarPrimitive::from_bytes_mod_order_nonzero(&shifted_order).is_none()
+    ));
+
+    let scalar = ScalarPrimitive::from_bytes_mod_order_nonzero(&[0xff; 40]).unwrap();
+    let expected = ScalarPrimitive::from(256u64).pow([40]) - ScalarPrimitive::ONE;
+    assert_eq!(scalar, expected);
+
+    assert_eq!(
+        ScalarPrimitive::from_bytes_mod_order_nonzero(&[0, 0, 7]).unwrap(),
+        ScalarPrimitive::from(7u64)
+    );
+}
+
+#[test]
+fn invert_fermat_with_chain() {
+    // Fixed 4-bit window chain for `n - 2`, with `r[i] = x^i` for `i > 0`
+    let mut exp = MODULUS_MINUS_ONE;
+    exp[31] -= 1;
+    let nibbles = exp.iter().flat_map(|byte| [byte >> 4, byte & 0xf]);
+
+    let mut steps = (2..AddChain::REGISTERS)
+        .map(|i| AddChainStep::Mul {
+            dst: i,
+            a: i - 1,
+            b: 1,
+        })
+        .collect::<Vec<_>>();
+
+    for (i, nibble) in nibbles.enumerate() {
+        if i == 0 {
+            steps.push(AddChainStep::Square {
+                dst: 0,
+                src: nibble.into(),
+                times: 0,
+            });
+            continue;
+        }
+
+        steps.push(AddChainStep::Square {
+            dst: 0,
+            src: 0,
+            times: 4,
+        });
+
+        if nibble != 0 {
+            steps.push(AddChainStep::Mul {
+                dst: 0,
+                a: 0,
+                b: nibble.into(),
+            });
+        }
+    }
+
+    let chain = AddChain::new(&steps);
+
+    for x in [
+        ScalarPrimitive::ONE,
+        ScalarPrimitive::from(0xdead_beefu64),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap(),
+    ] {
+        let inverse = x.invert_fermat_with_chain(&chain).unwrap();
+        assert_eq!(inverse, x.invert_fermat().unwrap());
+        assert_eq!(inverse * x, ScalarPrimitive::ONE);
+    }
+
+    assert!(bool::from(
+        ScalarPrimitive::ZERO
+            .invert_fermat_with_chain(&chain)
+            .is_none()
+    ));
+}
+
+#[test]
+fn verify_eq() {
+    let a = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert!(bool::from(a.verify_eq(&a)));
+
+    // Differs only in the most significant limb
+    let mut bytes = MODULUS_MINUS_ONE;
+    bytes[0] ^= 0x80;
+    let b = ScalarPrimitive::from_be_slice(&bytes).unwrap();
+    assert!(!bool::from(a.verify_eq(&b)));
+    assert!(!bool::from(b.verify_eq(&a)));
+}
+
+#[test]
+fn split_additive() {
+    let secret = ScalarPrimitive::random(&mut OsRng);
+
+    for n in [1, 2, 5] {
+        let shares = secret.split_additive(&mut OsRng, n);
+        assert_eq!(shares.len(), n);
+        assert_eq!(ScalarPrimitive::sum_shares(&shares), secret);
+    }
+
+    assert_eq!(ScalarPrimitive::sum_shares(&[]), ScalarPrimitive::ZERO);
+}
+
+#[test]
+fn halve() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let two = ScalarPrimitive::from(2u64);
+
+    assert_eq!(two.halve(), ScalarPrimitive::ONE);
+    assert_eq!(ScalarPrimitive::ZERO.halve(), ScalarPrimitive::ZERO);
+    assert_eq!(ScalarPrimitive::ONE.halve() * two, ScalarPrimitive::ONE);
+    assert_eq!(minus_one.halve() * two, minus_one);
+}
+
+#[test]
+fn halve_times() {
+    let x =
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap() - ScalarPrimitive::from(2u64);
+
+    assert_eq!(x.halve_times(0), x);
+    assert_eq!(x.halve_times(4) * ScalarPrimitive::from(16u64), x);
+    assert_eq!(
+        x.halve_times(100) * ScalarPrimitive::from(2u64).pow([100]),
+        x
+    );
+}
+
+#[test]
+fn invert_blinded() {
+    for x in [
+        ScalarPrimitive::ONE,
+        ScalarPrimitive::from(0xdead_beefu64),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap(),
+        ScalarPrimitive::random(&mut OsRng),
+    ] {
+        assert_eq!(x.invert_blinded(&mut OsRng).unwrap(), x.invert().unwrap());
+    }
+
+    assert!(bool::from(
+        ScalarPrimitive::ZERO.invert_blinded(&mut OsRng).is_none()
+    ));
+}
+
+#[test]
+fn ct_select() {
+    let mut table = [ScalarPrimitive::ZERO; 8];
+
+    for (i, entry) in table.iter_mut().enumerate() {
+        *entry = ScalarPrimitive::from(i as u64 * 3 + 1);
+    }
+
+    for (i, entry) in table.iter().enumerate() {
+        assert_eq!(&ScalarPrimitive::ct_select(&table, i as u8), entry);
+    }
+
+    assert_eq!(ScalarPrimitive::ct_select(&table, 8), ScalarPrimitive::ZERO);
+    assert_eq!(ScalarPrimitive::ct_select(&[], 0), ScalarPrimitive::ZERO);
+}
+
+#[test]
+fn from_i128() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(ScalarPrimitive::from_i128(0), ScalarPrimitive::ZERO);
+    assert_eq!(ScalarPrimitive::from_i128(5), ScalarPrimitive::from(5u64));
+    assert_eq!(ScalarPrimitive::from_i128(-1), minus_one);
+    assert_eq!(
+        ScalarPrimitive::from_i128(-3) + ScalarPrimitive::from(3u64),
+        ScalarPrimitive::ZERO
+    );
+
+    let two_64 = ScalarPrimitive::from(2u64).pow([64]);
+    assert_eq!(
+        ScalarPrimitive::from_i128(i128::MAX),
+        two_64 * two_64.halve() - ScalarPrimitive::ONE
+    );
+    assert_eq!(
+        ScalarPrimitive::from_i128(i128::MIN),
+        -(two_64 * two_64.halve())
+    );
+
+    assert_eq!(ScalarPrimitive::from_i64(-7), -ScalarPrimitive::from(7u64));
+    assert_eq!(ScalarPrimitive::from_i64(i64::MIN), -two_64.halve());
+
+    // Values larger than the order are reduced
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    assert_eq!(Small::from_i128(1_000_004), Small::ONE);
+    assert_eq!(Small::from_i128(-1_000_004), -Small::ONE);
+}
+
+#[test]
+fn truncate_bits() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(minus_one.truncate_bits(0), ScalarPrimitive::ZERO);
+    assert_eq!(minus_one.truncate_bits(4), ScalarPrimitive::ZERO);
+    assert_eq!(minus_one.truncate_bits(5), ScalarPrimitive::from(0x10u64));
+    assert_eq!(
+        minus_one.truncate_bits(64),
+        ScalarPrimitive::from(0xf3b9cac2fc632550u64)
+    );
+    assert_eq!(minus_one.truncate_bits(256), minus_one);
+    assert_eq!(minus_one.truncate_bits(1000), minus_one);
+
+    let mut expected = MODULUS_MINUS_ONE;
+    expected[..3].copy_from_slice(&[0, 0, 0x7f]);
+    assert_eq!(
+        minus_one.truncate_bits(239),
+        ScalarPrimitive::from_be_slice(&expected).unwrap()
+    );
+}
+
+#[test]
+fn ct_eq_abs() {
+    let a = ScalarPrimitive::from(0xdead_beefu64);
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert!(bool::from(a.ct_eq_abs(&a)));
+    assert!(bool::from(a.ct_eq_abs(&-a)));
+    assert!(bool::from((-a).ct_eq_abs(&a)));
+    assert!(bool::from(minus_one.ct_eq_abs(&ScalarPrimitive::ONE)));
+    assert!(bool::from(
+        ScalarPrimitive::ZERO.ct_eq_abs(&ScalarPrimitive::ZERO)
+    ));
+
+    assert!(!bool::from(a.ct_eq_abs(&ScalarPrimitive::ONE)));
+    assert!(!bool::from(a.ct_eq_abs(&(a + ScalarPrimitive::ONE))));
+    assert!(!bool::from(a.ct_eq_abs(&ScalarPrimitive::ZERO)));
+}
+
+#[test]
+fn complement() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let a = ScalarPrimitive::from(0xdead_beefu64);
+
+    assert_eq!(ScalarPrimitive::ZERO.complement(), minus_one);
+    assert_eq!(minus_one.complement(), ScalarPrimitive::ZERO);
+    assert_eq!(a.complement(), minus_one - a);
+    assert_eq!(a.complement(), -a - ScalarPrimitive::ONE);
+    assert_eq!(a.complement().complement(), a);
+}
+
+#[test]
+fn normalize_low_s() {
+    let half = ScalarPrimitive::new(MockCurve::ORDER >> 1).unwrap();
+    let half_plus_one = half + ScalarPrimitive::ONE;
+    let low = ScalarPrimitive::from(0xdead_beefu64);
+
+    assert_eq!(half.normalize_low_s(), half);
+    assert_eq!(half_plus_one.normalize_low_s(), -half_plus_one);
+    assert_eq!(half_plus_one.normalize_low_s(), half);
+    assert_eq!(low.normalize_low_s(), low);
+    assert_eq!((-low).normalize_low_s(), low);
+
+    let (normalized, flag) = half.normalize_low_s_flagged();
+    assert_eq!(normalized, half);
+    assert!(!bool::from(flag));
+
+    let (normalized, flag) = half_plus_one.normalize_low_s_flagged();
+    assert_eq!(normalized, half);
+    assert!(bool::from(flag));
+}
+
+#[test]
+fn signed_cmp() {
+    use core::cmp::Ordering;
+
+    let half = ScalarPrimitive::new(MockCurve::ORDER >> 1).unwrap();
+    let half_plus_one = half + ScalarPrimitive::ONE;
+    let minus_one = -ScalarPrimitive::ONE;
+
+    // `n/2 + 1` is the most negative value, while `n/2` is the largest
+    assert_eq!(half.signed_cmp(&half_plus_one), Ordering::Greater);
+    assert_eq!(half_plus_one.signed_cmp(&half), Ordering::Less);
+    assert_eq!(half_plus_one.signed_cmp(&minus_one), Ordering::Less);
+    assert_eq!(half.cmp(&half_plus_one), Ordering::Less);
+
+    assert_eq!(minus_one.signed_cmp(&ScalarPrimitive::ZERO), Ordering::Less);
+    assert_eq!(
+        ScalarPrimitive::ZERO.signed_cmp(&ScalarPrimitive::ONE),
+        Ordering::Less
+    );
+    assert_eq!(
+        minus_one.signed_cmp(&(minus_one - ScalarPrimitive::ONE)),
+        Ordering::Greater
+    );
+    assert_eq!(half.signed_cmp(&half), Ordering::Equal);
+}
+
+#[test]
+fn bit_stream_round_trip() {
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    assert_eq!(ScalarPrimitive::bits_needed(), 256);
+    assert_eq!(Small::bits_needed(), 20);
+
+    // Pack three small scalars into 60 bits, after a leading 256-bit scalar
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let small = [Small::from(1u64), Small::from(999_999u64), -Small::ONE];
+    let mut buf = [0u8; 40];
+    let mut writer = BitWriter::new(&mut buf);
+
+    minus_one.write_bits_le(&mut writer);
+    for scalar in &small {
+        scalar.write_bits_le(&mut writer);
+    }
+    assert_eq!(writer.bits_written(), 316);
+
+    assert_eq!(&buf[..32], minus_one.to_le_bytes().as_slice());
+    assert_eq!(buf[32], 1);
+    assert_eq!(buf[39], 0x0f);
+
+    let mut reader = BitReader::new(&buf);
+    assert_eq!(
+        ScalarPrimitive::from_bits_le_stream(&mut reader).unwrap(),
+        minus_one
+    );
+    for scalar in &small {
+        assert_eq!(&Small::from_bits_le_stream(&mut reader).unwrap(), scalar);
+    }
+    assert_eq!(reader.bits_read(), 316);
+    assert_eq!(reader.remaining(), 4);
+
+    // Not enough bits remaining
+    assert!(bool::from(
+        Small::from_bits_le_stream(&mut reader).is_none()
+    ));
+    assert_eq!(reader.bits_read(), 316);
+}
+
+#[test]
+fn bit_stream_out_of_range() {
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    let mut reader = BitReader::new(&[0xff; 3]);
+    assert!(bool::from(
+        Small::from_bits_le_stream(&mut reader).is_none()
+    ));
+}
+
+#[test]
+fn random_bounded() {
+    /// RNG which always outputs all-ones, i.e. values above the modulus.
+    struct SaturatedRng;
+
+    impl RngCore for SaturatedRng {
+        fn next_u32(&mut self) -> u32 {
+            u32::MAX
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            u64::MAX
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(0xff);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for SaturatedRng {}
+
+    assert!(ScalarPrimitive::random_bounded(&mut SaturatedRng, 100).is_none());
+    assert!(ScalarPrimitive::random_bounded(&mut OsRng, 0).is_none());
+    assert!(ScalarPrimitive::random_bounded(&mut OsRng, 100).is_some());
+
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    assert!(Small::random_bounded(&mut SaturatedRng, 100).is_none());
+    assert!(Small::random_bounded(&mut OsRng, 100).is_some());
+}
+
+#[test]
+fn add_be_bytes() {
+    let encoded = [
+        ScalarPrimitive::from(3u64).to_be_bytes(),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE)
+            .unwrap()
+            .to_be_bytes(),
+        ScalarPrimitive::from(0xdead_beefu64).to_be_bytes(),
+    ];
+
+    let sum = encoded.iter().fold(ScalarPrimitive::ZERO, |acc, bytes| {
+        acc.add_be_bytes(bytes).unwrap()
+    });
+    assert_eq!(sum, ScalarPrimitive::from(0xdead_bef1u64));
+
+    let out_of_range = GenericArray::from([0xff; 32]);
+    assert!(bool::from(sum.add_be_bytes(&out_of_range).is_none()));
+}
+
+#[test]
+fn opposite_endian_bytes() {
+    let scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap()
+        - ScalarPrimitive::from(0xdead_beefu64);
+    let reversed = scalar.to_opposite_endian_bytes();
+
+    assert_eq!(reversed, scalar.to_le_bytes());
+    assert_eq!(
+        ScalarPrimitive::from_reversed_bytes(&reversed).unwrap(),
+        scalar
+    );
+
+    let mut twice = reversed;
+    twice.reverse();
+    assert_eq!(twice, scalar.to_be_bytes());
+
+    let out_of_range = GenericArray::from([0xff; 32]);
+    assert!(bool::from(
+        ScalarPrimitive::from_reversed_bytes(&out_of_range).is_none()
+    ));
+}
+
+#[test]
+fn sub_be_bytes() {
+    let a = ScalarPrimitive::from(3u64);
+    let b = ScalarPrimitive::from(0xdead_beefu64);
+
+    assert_eq!(a.sub_be_bytes(&b.to_be_bytes()).unwrap(), a - b);
+    assert_eq!(
+        b.sub_be_bytes(&a.to_be_bytes()).unwrap(),
+        ScalarPrimitive::from(0xdead_beecu64)
+    );
+    assert_eq!(
+        a.sub_be_bytes(&a.to_be_bytes()).unwrap(),
+        ScalarPrimitive::ZERO
+    );
+
+    let out_of_range = GenericArray::from([0xff; 32]);
+    assert!(bool::from(a.sub_be_bytes(&out_of_range).is_none()));
+}
+
+#[test]
+fn parity_and_msb() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    for (scalar, parity) in [
+        (ScalarPrimitive::ZERO, 0),
+        (ScalarPrimitive::ONE, 1),
+        (ScalarPrimitive::from(0xdead_beefu64), 1),
+        (ScalarPrimitive::from(0xdead_beeeu64), 0),
+        (minus_one, 0),
+    ] {
+        assert_eq!(scalar.parity(), parity);
+        assert_eq!(scalar.ct_parity().unwrap_u8(), parity);
+    }
+
+    assert!(bool::from(minus_one.msb()));
+    assert!(!bool::from(ScalarPrimitive::ONE.msb()));
+    assert!(!bool::from(minus_one.halve().msb()));
+}
+
+#[test]
+fn rebase() {
+    /// Wrapper with the same order as `MockCurve`.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct WrappedCurve;
+
+    impl Curve for WrappedCurve {
+        type Uint = U256;
+
+        const ORDER: U256 = MockCurve::ORDER;
+    }
+
+    let scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let rebased = scalar.rebase::<WrappedCurve>().unwrap();
+    assert_eq!(rebased.to_be_bytes(), scalar.to_be_bytes());
+    assert_eq!(rebased.rebase::<MockCurve>().unwrap(), scalar);
+    assert_eq!(-rebased, (-scalar).rebase::<WrappedCurve>().unwrap());
+
+    let too_large = -elliptic_curve::ScalarPrimitive::<P256BaseField>::ONE;
+    assert!(bool::from(too_large.rebase::<WrappedCurve>().is_none()));
+}
+
+#[test]
+fn into_inner() {
+    let scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert_eq!(scalar.into_inner(), U256::from_be_slice(&MODULUS_MINUS_ONE));
+    assert_eq!(ScalarPrimitive::ONE.into_inner(), U256::ONE);
+}
+
+#[test]
+fn mul_2exp() {
+    let x =
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap() - ScalarPrimitive::from(2u64);
+
+    assert_eq!(x.double(), x + x);
+    assert_eq!(x.mul_2exp(0), x);
+    assert_eq!(x.mul_2exp(3), x.double().double().double());
+    assert_eq!(x.mul_2exp(300), x * ScalarPrimitive::from(2u64).pow([300]));
+    assert_eq!(x.mul_2exp(100).halve_times(100), x);
+}
+
+#[test]
+fn cumulative_products() {
+    let scalars = [
+        ScalarPrimitive::from(3u64),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap(),
+        ScalarPrimitive::from(0xdead_beefu64),
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::from(5u64),
+    ];
+
+    let mut expected = Vec::new();
+    let mut acc = ScalarPrimitive::ONE;
+    for scalar in &scalars {
+        acc *= scalar;
+        expected.push(acc);
+    }
+
+    assert_eq!(ScalarPrimitive::cumulative_products(&scalars), expected);
+
+    let mut in_place = scalars;
+    ScalarPrimitive::cumulative_products_in_place(&mut in_place);
+    assert_eq!(in_place[..], expected[..]);
+
+    assert!(ScalarPrimitive::cumulative_products(&[]).is_empty());
+}
+
+#[test]
+fn to_hex_array() {
+    let scalar = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+
+    assert_eq!(
+        &scalar.to_lower_hex_array()[..],
+        format!("{:x}", scalar).as_bytes()
+    );
+    assert_eq!(
+        &scalar.to_upper_hex_array()[..],
+        format!("{:X}", scalar).as_bytes()
+    );
+    assert_eq!(
+        &ScalarPrimitive::ONE.to_lower_hex_array()[..],
+        format!("{:0>64}", 1).as_bytes()
+    );
+}
+
+#[test]
+fn batch_mul() {
+    let a = [
+        ScalarPrimitive::from(3u64),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap(),
+        ScalarPrimitive::from(0xdead_beefu64),
+    ];
+    let b = [
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap(),
+        ScalarPrimitive::from(0xcafe_babeu64),
+        ScalarPrimitive::ZERO,
+    ];
+    let expected: Vec<_> = a.iter().zip(&b).map(|(x, y)| *x * y).collect();
+
+    let mut out = [ScalarPrimitive::ONE; 3];
+    assert_eq!(ScalarPrimitive::batch_mul(&a, &b, &mut out), Ok(()));
+    assert_eq!(out[..], expected[..]);
+    assert_eq!(ScalarPrimitive::batch_mul_vec(&a, &b), Ok(expected));
+
+    assert_eq!(
+        ScalarPrimitive::batch_mul(&a, &b[..2], &mut out),
+        Err(Error)
+    );
+    assert_eq!(
+        ScalarPrimitive::batch_mul(&a, &b, &mut out[..2]),
+        Err(Error)
+    );
+    assert_eq!(ScalarPrimitive::batch_mul_vec(&a[..1], &b), Err(Error));
+}
+
+#[test]
+fn powers() {
+    let x =
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap() - ScalarPrimitive::from(2u64);
+    let powers = x.powers(10);
+
+    assert_eq!(powers.len(), 10);
+    for (i, power) in powers.iter().enumerate() {
+        assert_eq!(*power, x.pow_vartime([i as u64]));
+    }
+
+    let mut buf = [ScalarPrimitive::ZERO; 10];
+    x.powers_into(&mut buf);
+    assert_eq!(buf[..], powers[..]);
+
+    assert_eq!(ScalarPrimitive::ZERO.powers(3)[0], ScalarPrimitive::ONE);
+    assert!(x.powers(0).is_empty());
+}
+
+#[test]
+fn inv_powers() {
+    let x = ScalarPrimitive::from(0xdead_beefu64);
+    let powers = x.powers(10);
+    let inv_powers = x.inv_powers(10).unwrap();
+
+    assert_eq!(inv_powers.len(), 10);
+    for (power, inv_power) in powers.iter().zip(&inv_powers) {
+        assert_eq!(*power * inv_power, ScalarPrimitive::ONE);
+    }
+
+    assert!(bool::from(ScalarPrimitive::ZERO.inv_powers(10).is_none()));
+}
+
+#[test]
+fn from_bytes_mod_order_wide_le() {
+    /// Curve whose order is the Ed25519 group order `l`.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct Ed25519Order;
+
+    impl Curve for Ed25519Order {
+        type Uint = U256;
+
+        const ORDER: U256 =
+            U256::from_be_hex("1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed");
+    }
+
+    type Ed25519Scalar = elliptic_curve::ScalarPrimitive<Ed25519Order>;
+
+    let mut bytes = GenericArray::default();
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    assert_eq!(
+        Ed25519Scalar::from_bytes_mod_order_wide_le(&bytes).to_be_bytes()[..],
+        hex!("0572d0e474b5e0da7a932112c3d46159cce628540db62350a0372df082623c7a")
+    );
+
+    let bytes = GenericArray::from([0xff; 64]);
+    assert_eq!(
+        Ed25519Scalar::from_bytes_mod_order_wide_le(&bytes).to_be_bytes()[..],
+        hex!("0399411b7c309a3dceec73d217f5be65d00e1ba768859347a40611e3449c0f00")
+    );
+
+    // Agrees with the big endian reduction
+    let mut be = [0u8; 64];
+    be[32..].copy_from_slice(&MODULUS_MINUS_ONE);
+    let mut le = be;
+    le.reverse();
+    assert_eq!(
+        ScalarPrimitive::from_bytes_mod_order_wide_le(&GenericArray::from(le)),
+        ScalarPrimitive::from_bytes_mod_order_nonzero(&be).unwrap()
+    );
+}
+
+#[test]
+fn reduce_be_slice_ct() {
+    let two_8 = ScalarPrimitive::from(256u64);
+
+    assert_eq!(
+        ScalarPrimitive::reduce_be_slice_ct(&[]),
+        ScalarPrimitive::ZERO
+    );
+    assert_eq!(
+        ScalarPrimitive::reduce_be_slice_ct(&[1, 2]),
+        ScalarPrimitive::from(0x0102u64)
+    );
+    assert_eq!(
+        ScalarPrimitive::reduce_be_slice_ct(&MODULUS_MINUS_ONE),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap()
+    );
+
+    for len in [31, 32, 33, 48, 64, 100] {
+        let expected = two_8.pow([len]) - ScalarPrimitive::ONE;
+        assert_eq!(
+            ScalarPrimitive::reduce_be_slice_ct(&vec![0xff; len as usize]),
+            expected
+        );
+    }
+
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    assert_eq!(
+        Small::reduce_be_slice_ct(&3_000_010u64.to_be_bytes()),
+        Small::from(1u64)
+    );
+
+    // Compare against byte-wise Horner evaluation for lengths which aren't a
+    // multiple of the chunk size
+    for len in [1, 7, 9, 17, 63, 65] {
+        let mut bytes = vec![0u8; len];
+        OsRng.fill_bytes(&mut bytes);
+
+        let expected = bytes.iter().fold(ScalarPrimitive::ZERO, |acc, &byte| {
+            acc * two_8 + ScalarPrimitive::from(u64::from(byte))
+        });
+        assert_eq!(ScalarPrimitive::reduce_be_slice_ct(&bytes), expected);
+
+        let expected = bytes.iter().fold(Small::ZERO, |acc, &byte| {
+            acc * Small::from(256u64) + Small::from(u64::from(byte))
+        });
+        assert_eq!(Small::reduce_be_slice_ct(&bytes), expected);
+    }
+}
+
+#[test]
+fn format_for_ladder() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert_eq!(
+        minus_one.format_for_ladder(3, 254),
+        ScalarPrimitive::from_be_bytes_masked(MODULUS_MINUS_ONE.into(), 3, 1, Some(254))
+    );
+
+    // X25519-style formatting on a 20-bit order, below which no reduction occurs
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    let scalar = Small::from(0b1011_0110_1101_1011_1111u64);
+    assert_eq!(
+        scalar.format_for_ladder(3, 18),
+        Small::from(0b0111_0110_1101_1011_1000u64)
+    );
+    assert_eq!(
+        Small::ZERO.format_for_ladder(3, 18),
+        Small::from(1u64 << 18)
+    );
+    assert_eq!(Small::ONE.format_for_ladder(0, 0), Small::ONE);
+}
+
+#[test]
+fn reduce_signed_wide() {
+    let n_minus_one = U256::from_be_slice(&MODULUS_MINUS_ONE);
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let seven = ScalarPrimitive::from(7u64);
+
+    // Below the order
+    assert_eq!(
+        ScalarPrimitive::reduce_signed_wide(&U512::from(7u64), false),
+        seven
+    );
+    assert_eq!(
+        ScalarPrimitive::reduce_signed_wide(&U512::from(7u64), true),
+        -seven
+    );
+    assert_eq!(
+        ScalarPrimitive::reduce_signed_wide(&U512::ZERO, true),
+        ScalarPrimitive::ZERO
+    );
+
+    // Above the order: (n - 1)^2 = 1 (mod n)
+    let (lo, hi) = n_minus_one.mul_wide(&n_minus_one);
+    assert_eq!(
+        ScalarPrimitive::reduce_signed_wide(&hi.concat(&lo), false),
+        ScalarPrimitive::ONE
+    );
+    assert_eq!(
+        ScalarPrimitive::reduce_signed_wide(&hi.concat(&lo), true),
+        minus_one
+    );
+
+    // The order itself
+    let n = U256::ZERO.concat(&MockCurve::ORDER);
+    assert_eq!(
+        ScalarPrimitive::reduce_signed_wide(&n, true),
+        ScalarPrimitive::ZERO
+    );
+}
+
+#[test]
+fn u64_digits() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let be = [
+        0xffffffff00000000,
+        0xffffffffffffffff,
+        0xbce6faada7179e84,
+        0xf3b9cac2fc632550,
+    ];
+
+    assert_eq!(minus_one.to_u64_digits_be(), be);
+    assert_eq!(ScalarPrimitive::from_u64_digits_be(&be).unwrap(), minus_one);
+
+    // Little endian words are the reverse
+    let mut le = minus_one.to_u64_digits_le();
+    assert_eq!(ScalarPrimitive::from_u64_digits_le(&le).unwrap(), minus_one);
+    le.reverse();
+    assert_eq!(le, be);
+
+    // Short and zero-extended inputs
+    assert_eq!(
+        ScalarPrimitive::from_u64_digits_be(&[0, 0, 0, 0, 1, 2]).unwrap(),
+        ScalarPrimitive::from(2u64) + ScalarPrimitive::from(2u64).pow([64])
+    );
+    assert_eq!(
+        ScalarPrimitive::from_u64_digits_le(&[]).unwrap(),
+        ScalarPrimitive::ZERO
+    );
+
+    // Out of range
+    assert!(bool::from(
+        ScalarPrimitive::from_u64_digits_be(&[u64::MAX; 4]).is_none()
+    ));
+    assert!(bool::from(
+        ScalarPrimitive::from_u64_digits_be(&[1, 0, 0, 0, 0]).is_none()
+    ));
+
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    assert_eq!(Small::from(7u64).to_u64_digits_be(), [7]);
+    assert!(bool::from(
+        Small::from_u64_digits_le(&[1_000_003]).is_none()
+    ));
+}
+
+#[test]
+fn random_distinct() {
+    let mut scalars = [ScalarPrimitive::ZERO; 16];
+    ScalarPrimitive::random_distinct(&mut OsRng, &mut scalars);
+
+    for (i, a) in scalars.iter().enumerate() {
+        assert!(!bool::from(a.is_zero()));
+        assert!(scalars[(i + 1)..].iter().all(|b| a != b));
+    }
+}
+
+#[test]
+fn random_coprime() {
+    // 2 * 3 * 5 * 7 * 11 * 13, so roughly 80% of samples are rejected
+    let modulus = U256::from(30030u64);
+
+    for _ in 0..32 {
+        let scalar = ScalarPrimitive::random_coprime(&mut OsRng, &modulus);
+
+        for p in [2u64, 3, 5, 7, 11, 13] {
+            let p = NonZero::new(U256::from(p)).unwrap();
+            assert_ne!(scalar.reduce_mod(&p), U256::ZERO);
+        }
+    }
+
+    // Zero is never coprime to the order
+    let scalar = ScalarPrimitive::random_coprime(&mut OsRng, &MockCurve::ORDER);
+    assert!(!bool::from(scalar.is_zero()));
+
+    // Odd moduli
+    let modulus = U256::from(3u64 * 5 * 7 * 9);
+
+    for _ in 0..32 {
+        let scalar = ScalarPrimitive::random_coprime(&mut OsRng, &modulus);
+
+        for p in [3u64, 5, 7] {
+            let p = NonZero::new(U256::from(p)).unwrap();
+            assert_ne!(scalar.reduce_mod(&p), U256::ZERO);
+        }
+    }
+
+    // Every scalar is coprime to one, and only odd ones to a power of two
+    ScalarPrimitive::random_coprime(&mut OsRng, &U256::ONE);
+    let scalar = ScalarPrimitive::random_coprime(&mut OsRng, &U256::from(1024u64));
+    assert!(bool::from(scalar.is_odd()));
+}
+
+#[test]
+#[should_panic(expected = "modulus must be non-zero")]
+fn random_coprime_zero_modulus() {
+    ScalarPrimitive::random_coprime(&mut OsRng, &U256::ZERO);
+}
+
+#[test]
+fn random_distinct_bounded() {
+    /// Curve with order 3, which has only two non-zero scalars.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct TinyCurve;
+
+    impl Curve for TinyCurve {
+        type Uint = U64;
+
+        const ORDER: U64 = U64::from_u64(3);
+    }
+
+    type Tiny = elliptic_curve::ScalarPrimitive<TinyCurve>;
+
+    let mut pair = [Tiny::ZERO; 2];
+    assert_eq!(
+        Tiny::random_distinct_bounded(&mut OsRng, &mut pair, 1000),
+        Some(())
+    );
+    assert!(pair.contains(&Tiny::ONE) && pair.contains(&Tiny::from(2u64)));
+
+    let mut triple = [Tiny::ZERO; 3];
+    assert_eq!(
+        Tiny::random_distinct_bounded(&mut OsRng, &mut triple, 1000),
+        None
+    );
+
+    let mut scalars = [ScalarPrimitive::ZERO; 4];
+    assert_eq!(
+        ScalarPrimitive::random_distinct_bounded(&mut OsRng, &mut scalars, 3),
+        None
+    );
+    assert_eq!(
+        ScalarPrimitive::random_distinct_bounded(&mut OsRng, &mut scalars, 100),
+        Some(())
+    );
+}
+
+#[test]
+fn to_transcript_bytes() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let mut transcript = b"prefix".to_vec();
+    minus_one.to_transcript_bytes(b"challenge", &mut transcript);
+
+    assert_eq!(
+        transcript,
+        [
+            b"prefix".as_ref(),
+            b"challenge",
+            &[32, 0, 0, 0],
+            &MODULUS_MINUS_ONE
+        ]
+        .concat()
+    );
+
+    type Small = elliptic_curve::ScalarPrimitive<SmallCurve>;
+    let mut transcript = Vec::new();
+    Small::from(0x0102u64).to_transcript_bytes(b"", &mut transcript);
+    assert_eq!(transcript, [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2]);
+}
+
+#[test]
+fn from_field_bytes_reduced() {
+    // `x = p - 1` for the P-256 base field modulus `p`, which exceeds `n`
+    let x = hex!("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe");
+    assert!(ScalarPrimitive::from_be_slice(&x).is_err());
+    assert_eq!(
+        ScalarPrimitive::from_field_bytes_reduced(&x.into()).to_be_bytes()[..],
+        hex!("000000000000000000000000000000004319055358e8617b0c46353d039cdaad")
+    );
+
+    // Values below the order are unchanged
+    assert_eq!(
+        ScalarPrimitive::from_field_bytes_reduced(&MODULUS_MINUS_ONE.into()),
+        ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap()
+    );
+    assert_eq!(
+        ScalarPrimitive::from_field_bytes_reduced(&Default::default()),
+        ScalarPrimitive::ZERO
+    );
+}
+
+#[test]
+fn ct_le_uint() {
+    let bound = U256::from(1000u64);
+
+    assert!(bool::from(ScalarPrimitive::ZERO.ct_le_uint(&bound)));
+    assert!(bool::from(ScalarPrimitive::from(999u64).ct_le_uint(&bound)));
+    assert!(bool::from(
+        ScalarPrimitive::from(1000u64).ct_le_uint(&bound)
+    ));
+    assert!(!bool::from(
+        ScalarPrimitive::from(1001u64).ct_le_uint(&bound)
+    ));
+    assert!(!bool::from((-ScalarPrimitive::ONE).ct_le_uint(&bound)));
+
+    assert!(bool::from((-ScalarPrimitive::ONE).ct_le_uint(&U256::MAX)));
+    assert!(!bool::from(ScalarPrimitive::ONE.ct_le_uint(&U256::ZERO)));
+}
+
+#[test]
+fn ct_lt_order_fraction() {
+    let one_third = ScalarPrimitive::from_be_slice(&hex!(
+        "555555550000000055555555555555553ef7a8e48d07df81a693439654210c70"
+    ))
+    .unwrap();
+    let two_thirds = ScalarPrimitive::from_be_slice(&hex!(
+        "aaaaaaaa00000000aaaaaaaaaaaaaaaa7def51c91a0fbf034d26872ca84218e0"
+    ))
+    .unwrap();
+
+    for (threshold, numerator) in [(one_third, 1), (two_thirds, 2)] {
+        let below = threshold - ScalarPrimitive::ONE;
+        assert!(bool::from(below.ct_lt_order_fraction(numerator, 3)));
+        assert!(!bool::from(threshold.ct_lt_order_fraction(numerator, 3)));
+        assert!(!bool::from(
+            (threshold + ScalarPrimitive::ONE).ct_lt_order_fraction(numerator, 3)
+        ));
+    }
+
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert!(bool::from(minus_one.ct_lt_order_fraction(1, 1)));
+    assert!(bool::from(minus_one.ct_lt_order_fraction(5, 3)));
+    assert!(!bool::from(
+        ScalarPrimitive::ZERO.ct_lt_order_fraction(0, 3)
+    ));
+
+    // The `1/2` case complements `IsHigh`, except at `floor(n / 2)` itself
+    use elliptic_curve::IsHigh;
+    let half = minus_one.halve();
+    for scalar in [half - ScalarPrimitive::ONE, half + ScalarPrimitive::ONE] {
+        assert_eq!(
+            bool::from(scalar.ct_lt_order_fraction(1, 2)),
+            !bool::from(scalar.is_high())
+        );
+    }
+    assert!(!bool::from(half.ct_lt_order_fraction(1, 2)));
+    assert!(!bool::from(half.is_high()));
+}
+
+#[test]
+fn u32_words() {
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    let words = [
+        0xfc632550, 0xf3b9cac2, 0xa7179e84, 0xbce6faad, 0xffffffff, 0xffffffff, 0x00000000,
+        0xffffffff,
+    ];
+
+    assert_eq!(minus_one.to_u32_words_le(), words);
+    assert_eq!(ScalarPrimitive::from_u32_words_le(&words), Ok(minus_one));
+
+    let mut out = [0; 8];
+    ScalarPrimitive::ONE.to_u32_words_le_into(&mut out);
+    assert_eq!(out, [1, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        ScalarPrimitive::from_u32_words_le(&out),
+        Ok(ScalarPrimitive::ONE)
+    );
+
+    assert_eq!(ScalarPrimitive::from_u32_words_le(&out[..7]), Err(Error));
+    assert_eq!(
+        ScalarPrimitive::from_u32_words_le(&[u32::MAX; 8]),
+        Err(Error)
+    );
+}
+
+#[test]
+#[should_panic]
+fn u32_words_wrong_length() {
+    ScalarPrimitive::ONE.to_u32_words_le_into(&mut [0; 4]);
+}
+
+#[test]
+fn invert_vartime() {
+    for _ in 0..32 {
+        let x = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(x.invert_vartime().unwrap(), x.invert().unwrap());
+    }
+
+    let minus_one = ScalarPrimitive::from_be_slice(&MODULUS_MINUS_ONE).unwrap();
+    assert_eq!(minus_one.invert_vartime().unwrap(), minus_one);
+    assert_eq!(
+        ScalarPrimitive::ONE.invert_vartime().unwrap(),
+        ScalarPrimitive::ONE
+    );
+    assert!(bool::from(ScalarPrimitive::ZERO.invert_vartime().is_none()));
+}
+
+#[test]
+fn nonzero_from_bytes_mod_order() {
+    // `256 * n` reduces to zero
+    let mut shifted_order = [0u8; 33];
+    shifted_order[..32].copy_from_slice(&hex!(
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+    ));
+    assert!(bool::from(
+        ScalarPrimitive::nonzero_from_bytes_mod_order(&shifted_order).is_none()
+    ));
+    assert!(bool::from(
+        ScalarPrimitive::nonzero_from_bytes_mod_order(&[0; 48]).is_none()
+    ));
+
+    let seed = [0xffu8; 48];
+    let scalar = ScalarPrimitive::nonzero_from_bytes_mod_order(&seed).unwrap();
+    assert_eq!(
+        ScalarPrimitive::from_scalar(&scalar),
+        ScalarPrimitive::reduce_be_slice_ct(&seed)
+    );
+}
+
+#[test]
+fn sqrt_ratio() {
+    // Smallest non-square modulo the P-256 order
+    let z = ScalarPrimitive::from(7u64);
+    assert_eq!(MockCurve::Z, z);
+    assert!(bool::from(ScalarPrimitive::new(*z.as_uint()).is_some()));
+
+    // Vectors computed independently by following RFC 9380 Appendix F.2.1.1
+    for (u, v, is_square, root) in [
+        (
+            4,
+            1,
+            true,
+            hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254f"),
+        ),
+        (
+            2,
+            1,
+            true,
+            hex!("60078e8990bd8fb19b5d1a5c70275ff7863d3ee77ed1fc543390a9fbcef94265"),
+        ),
+        (
+            5,
+            7,
+            false,
+            hex!("535d57b61b345e86f138d6b2c96396c4779eac8888632f030eca4844623f2401"),
+        ),
+        (
+            0xdeadbeef,
+            3,
+            true,
+            hex!("869b754b91367602febe47f68e2bfbc3c15dd0f9c1c3611283e66e0aaddc0f24"),
+        ),
+        (
+            7,
+            1,
+            false,
+            hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254a"),
+        ),
+    ] {
+        let (u, v) = (ScalarPrimitive::from(u), ScalarPrimitive::from(v));
+        let root = ScalarPrimitive::from_be_slice(&root).unwrap();
+
+        let (choice, result) = ScalarPrimitive::sqrt_ratio(&u, &v);
+        assert_eq!(bool::from(choice), is_square);
+        assert_eq!(result, root);
+        let (choice, result) = ScalarPrimitive::sqrt_ratio_with_z(&u, &v, &z);
+        assert_eq!(bool::from(choice), is_square);
+        assert_eq!(result, root);
+
+        let expected = if is_square { u } else { z * u };
+        assert_eq!(root * root * v, expected);
+    }
+
+    assert!(bool::from(ScalarPrimitive::from(7u64).sqrt().is_none()));
+    assert_eq!(
+        ScalarPrimitive::from(4u64).sqrt().unwrap(),
+        -ScalarPrimitive::from(2u64)
+    );
+    assert_eq!(ScalarPrimitive::ZERO.sqrt().unwrap(), ScalarPrimitive::ZERO);
+}
+
+#[cfg(feature = "getrandom")]
+#[test]
+fn from_entropy() {
+    let a = ScalarPrimitive::from_entropy();
+    let b = ScalarPrimitive::from_entropy();
+
+    assert!(bool::from(ScalarPrimitive::new(a.to_uint()).is_some()));
+    assert_ne!(a, b);
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn derive_with() {
+    use sha2::{Digest, Sha256};
+
+    let seed = b"example derivation seed";
+    let a = ScalarPrimitive::derive_with::<Sha256>(seed, 0);
+
+    assert_eq!(a, ScalarPrimitive::derive_with::<Sha256>(seed, 0));
+    assert_ne!(a, ScalarPrimitive::derive_with::<Sha256>(seed, 1));
+    assert_ne!(a, ScalarPrimitive::derive_with::<Sha256>(b"other seed", 0));
+
+    let mut input = seed.to_vec();
+    input.extend_from_slice(&[0; 8]);
+    let digest = Sha256::digest(&input);
+    assert_eq!(a, ScalarPrimitive::reduce_be_slice_ct(&digest));
+
+    #[cfg(feature = "sha2")]
+    assert_eq!(ScalarPrimitive::derive(seed, 0), a);
+}